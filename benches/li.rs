@@ -1,5 +1,7 @@
 use num::complex::Complex;
-use polylog::{Li2, Li3, Li4, Li5, Li6, Li};
+use polylog::{Li1, Li2, Li2Orbit, Li3, Li4, Li5, Li6, Li, LiAll, Polylog, PolylogEval};
+use polylog::batch::{li2_slice, li_slice, li_slice_complex};
+use polylog::simd;
 use criterion::*;
 
 
@@ -21,6 +23,19 @@ fn bench_complex_li2(c: &mut Criterion) {
 }
 
 
+fn bench_complex_li2_orbit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("li2_orbit(z)");
+    for (name, z) in [("0.25+0.25i", Complex::new(0.25_f64, 0.25_f64)), ("-0.7+0.7i", Complex::new(-0.7_f64, 0.7_f64))].iter() {
+        group.bench_function(format!("separate,z={}", name), |b| b.iter(|| {
+            let z = black_box(*z);
+            [z.li2(), (1.0 - z).li2(), (1.0/z).li2(), (1.0/(1.0 - z)).li2(), (z/(z - 1.0)).li2(), ((z - 1.0)/z).li2()]
+        }));
+        group.bench_function(format!("orbit,z={}", name), |b| b.iter(|| black_box(*z).li2_orbit()));
+    }
+    group.finish();
+}
+
+
 fn bench_real_li3(c: &mut Criterion) {
     let mut group = c.benchmark_group("li3(x)");
     group.bench_function("x=0.25", |b| b.iter(|| black_box(0.25_f64).li3()));
@@ -103,13 +118,106 @@ fn bench_complex_li(c: &mut Criterion) {
 }
 
 
+fn bench_complex_li_all(c: &mut Criterion) {
+    let zi = [
+        ("0.25+0.25i", Complex::new(0.25_f64, 0.25_f64)),
+        ("-0.7+0.7i" , Complex::new(-0.7_f64,  0.7_f64)),
+        ("2.0+1.0i"  , Complex::new(2.0_f64,   1.0_f64)),
+    ];
+
+    let mut group = c.benchmark_group("li_all(n,z)");
+
+    for (name, z) in zi.iter() {
+        group.bench_function(format!("li1..li6,z={}", name), |b| b.iter(|| {
+            let z = black_box(*z);
+            [z.li1(), z.li2(), z.li3(), z.li4(), z.li5(), z.li6()]
+        }));
+        group.bench_function(format!("n=6,z={}", name), |b| b.iter(|| black_box(*z).li_all(6)));
+        group.bench_function(format!("li(1..10),z={}", name), |b| b.iter(|| {
+            let z = black_box(*z);
+            (1..=10).map(|k| z.li(k)).collect::<Vec<_>>()
+        }));
+        group.bench_function(format!("n=10,z={}", name), |b| b.iter(|| black_box(*z).li_all(10)));
+    }
+
+    group.finish();
+}
+
+
+fn bench_slice(c: &mut Criterion) {
+    let x: Vec<f64> = (0..1000).map(|i| -2.0 + 0.004*(i as f64)).collect();
+    let z: Vec<Complex<f64>> = x.iter().map(|&x| Complex::new(x, 0.5)).collect();
+    let mut rout = vec![0.0; x.len()];
+    let mut cout = vec![Complex::new(0.0, 0.0); z.len()];
+
+    let mut group = c.benchmark_group("li_slice(n,x[1000])");
+
+    group.bench_function("loop,n=2,x", |b| b.iter(|| {
+        for (o, x) in rout.iter_mut().zip(black_box(&x).iter()) { *o = x.li2(); }
+    }));
+    group.bench_function("slice,n=2,x", |b| b.iter(|| li2_slice(black_box(&x), &mut rout)));
+
+    for &n in [7, 10, 30].iter() {
+        group.bench_function(format!("loop,n={},x", n), |b| b.iter(|| {
+            for (o, x) in rout.iter_mut().zip(black_box(&x).iter()) { *o = x.li(n); }
+        }));
+        group.bench_function(format!("slice,n={},x", n), |b| b.iter(|| li_slice(n, black_box(&x), &mut rout)));
+        group.bench_function(format!("loop,n={},z", n), |b| b.iter(|| {
+            for (o, z) in cout.iter_mut().zip(black_box(&z).iter()) { *o = z.li(n); }
+        }));
+        group.bench_function(format!("slice,n={},z", n), |b| b.iter(|| li_slice_complex(n, black_box(&z), &mut cout)));
+    }
+
+    group.finish();
+}
+
+
+fn bench_polylog(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Polylog::eval");
+
+    for &n in [7, 10, 30].iter() {
+        let li = Polylog::new(n);
+        group.bench_function(format!("li,n={},x=0.9", n), |b| b.iter(|| black_box(0.9).li(n)));
+        group.bench_function(format!("eval,n={},x=0.9", n), |b| b.iter(|| li.eval(black_box(0.9))));
+        group.bench_function(format!("li,n={},z=(0.9,0.5)", n), |b| b.iter(|| black_box(Complex::new(0.9, 0.5)).li(n)));
+        group.bench_function(format!("eval,n={},z=(0.9,0.5)", n), |b| b.iter(|| li.eval(black_box(Complex::new(0.9, 0.5)))));
+    }
+
+    group.finish();
+}
+
+
+fn bench_simd(c: &mut Criterion) {
+    let x: Vec<f64> = (0..1000).map(|i| -4.0 + 0.008*(i as f64)).collect();
+    let mut out = vec![0.0; x.len()];
+
+    let mut group = c.benchmark_group("simd(x[1000])");
+
+    group.bench_function("loop,li2", |b| b.iter(|| {
+        for (o, x) in out.iter_mut().zip(black_box(&x).iter()) { *o = x.li2(); }
+    }));
+    group.bench_function("simd,li2", |b| b.iter(|| simd::li2_slice(black_box(&x), &mut out)));
+    group.bench_function("loop,li3", |b| b.iter(|| {
+        for (o, x) in out.iter_mut().zip(black_box(&x).iter()) { *o = x.li3(); }
+    }));
+    group.bench_function("simd,li3", |b| b.iter(|| simd::li3_slice(black_box(&x), &mut out)));
+
+    group.finish();
+}
+
+
 criterion_group!(benches,
                  bench_real_li2, bench_complex_li2,
+                 bench_complex_li2_orbit,
                  bench_real_li3, bench_complex_li3,
                  bench_real_li4, bench_complex_li4,
                  bench_complex_li5,
                  bench_complex_li6,
                  bench_real_li,
-                 bench_complex_li
+                 bench_complex_li,
+                 bench_complex_li_all,
+                 bench_slice,
+                 bench_polylog,
+                 bench_simd
 );
 criterion_main!(benches);
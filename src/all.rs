@@ -0,0 +1,101 @@
+use num::complex::Complex;
+use crate::cln::CLn;
+use crate::{Li, Li2};
+use crate::li::cli::cli_range;
+use crate::li3::{cli3_inverse, cli3_unit_circle, cli3_unity};
+use crate::li4::{cli4_inverse, cli4_unit_circle, cli4_unity};
+use crate::li5::{cli5_inverse, cli5_unit_circle, cli5_unity};
+use crate::li6::{cli6_inverse, cli6_unit_circle, cli6_unity};
+
+/// Provides the polylogarithms `li_all()` of all orders 1,...,n of a
+/// number z of type `T`, i.e. `z.li_all(n)` returns the vector
+/// [Li(1,z), Li(2,z), ..., Li(n,z)].
+///
+/// The results are identical to the ones of `Li`, but for complex z
+/// the quantities, which are needed by several orders, are calculated
+/// only once: the logarithms ln(z), ln(-ln(z)), -ln(1-z) and ln(-z) of
+/// the series expansions of Li3 to Li6 and the logarithms and 1/z of
+/// the expansions of the orders n > 6.
+pub trait LiAll<T> {
+    fn li_all(&self, n: i32) -> Vec<T>;
+}
+
+impl LiAll<f64> for f64 {
+    /// Returns the real polylogarithms [Re[Li(1,x)], ..., Re[Li(n,x)]]
+    /// of a real number x of type `f64`.  Returns an empty vector for
+    /// n < 1.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Li, LiAll};
+    ///
+    /// let li = 0.5_f64.li_all(4);
+    /// assert!(li.len() == 4 && li[3] == 0.5_f64.li(4));
+    /// ```
+    fn li_all(&self, n: i32) -> Vec<f64> {
+        (1..=n).map(|k| self.li(k)).collect()
+    }
+}
+
+impl LiAll<Complex<f64>> for Complex<f64> {
+    /// Returns the complex polylogarithms [Li(1,z), ..., Li(n,z)] of a
+    /// complex number z of type `Complex<f64>`.  Returns an empty
+    /// vector for n < 1.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiAll};
+    ///
+    /// let z = Complex::new(1.0_f64, 1.0_f64);
+    /// let li = z.li_all(6);
+    /// assert!(li.len() == 6 && li[2] == z.li(3));
+    /// ```
+    fn li_all(&self, n: i32) -> Vec<Complex<f64>> {
+        let z = *self;
+
+        if n < 1 {
+            return vec![];
+        } else if z.im == 0.0 || !z.is_finite() {
+            return (1..=n).map(|k| z.li(k)).collect();
+        }
+
+        let mut li = Vec::with_capacity(n as usize);
+        li.push(-(1.0 - z).cln());
+
+        if n >= 2 {
+            li.push(z.li2());
+        }
+
+        if n >= 3 {
+            let pi  = std::f64::consts::PI;
+            let nz  = z.norm();
+            let pz  = z.arg();
+            let lnz = nz.ln();
+            let nmax = n.min(6);
+
+            if lnz*lnz + pz*pz < 1.0 { // |log(z)| < 1
+                let u = Complex::new(lnz, pz);
+                let lnmu = (-u).cln();
+                let f = [cli3_unity, cli4_unity, cli5_unity, cli6_unity];
+                li.extend((3..=nmax).map(|k| f[(k - 3) as usize](u, lnmu)));
+            } else if nz <= 1.0 {
+                let x = li[0];
+                let f = [cli3_unit_circle, cli4_unit_circle, cli5_unit_circle, cli6_unit_circle];
+                li.extend((3..=nmax).map(|k| f[(k - 3) as usize](x)));
+            } else { // nz > 1.0
+                let arg = if pz > 0.0 { pz - pi } else { pz + pi };
+                let lmz = Complex::new(lnz, arg); // (-z).cln()
+                let x = -(1.0 - 1.0/z).cln();
+                let f = [cli3_inverse, cli4_inverse, cli5_inverse, cli6_inverse];
+                li.extend((3..=nmax).map(|k| f[(k - 3) as usize](x, lmz)));
+            }
+        }
+
+        if n >= 7 {
+            li.extend(cli_range(7, n, z));
+        }
+
+        li
+    }
+}
@@ -0,0 +1,189 @@
+//! Batch evaluation of polylogarithms
+//!
+//! The functions `li0_slice()`, ..., `li6_slice()` write the
+//! polylogarithms of fixed order of all elements of a slice into an
+//! output slice of the same length, for every type the corresponding
+//! trait `Li0`, ..., `Li6` is implemented for.  The functions
+//! `li_slice()` and `li_slice_complex()` evaluate the n-th order
+//! polylogarithm for a fixed order n, where the order-dependent
+//! coefficients of the series expansions are calculated only once for
+//! the whole slice.  The results are identical to the ones of the
+//! corresponding traits.
+//!
+//! # Example:
+//! ```
+//! use num::complex::Complex;
+//! use polylog::Li;
+//! use polylog::batch::{li2_slice, li_slice_complex};
+//!
+//! let x = [0.1, 0.5, 2.0];
+//! let mut out = [0.0; 3];
+//! li2_slice(&x, &mut out);
+//!
+//! let z = [Complex::new(0.5, 0.5), Complex::new(-2.0, 1.0)];
+//! let mut out = [Complex::new(0.0, 0.0); 2];
+//! li_slice_complex(10, &z, &mut out);
+//! assert!(out[1] == z[1].li(10));
+//! ```
+
+use num::complex::Complex;
+use crate::{Li0, Li1, Li2, Li3, Li4, Li5, Li6};
+use crate::li::cli::cli_table;
+use crate::li::rli::rli_table;
+use crate::li::table::Table;
+
+/// applies f to all elements of x and writes the results to out
+fn map_slice<T, F: Fn(&T) -> T>(name: &str, x: &[T], out: &mut [T], f: F) {
+    if x.len() != out.len() {
+        panic!("{} requires slices of equal length (given lengths: {} and {})", name, x.len(), out.len());
+    }
+
+    for (o, xi) in out.iter_mut().zip(x.iter()) {
+        *o = f(xi);
+    }
+}
+
+/// Writes the 0th order polylogarithms Li0(x) of all elements of `x`
+/// to `out`.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li0_slice<T: Li0<T>>(x: &[T], out: &mut [T]) {
+    map_slice("li0_slice", x, out, |xi| xi.li0())
+}
+
+/// Writes the 1st order polylogarithms Li1(x) of all elements of `x`
+/// to `out`.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li1_slice<T: Li1<T>>(x: &[T], out: &mut [T]) {
+    map_slice("li1_slice", x, out, |xi| xi.li1())
+}
+
+/// Writes the dilogarithms Li2(x) of all elements of `x` to `out`.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::Li2;
+/// use polylog::batch::li2_slice;
+///
+/// let z = [Complex::new(1.0_f32, 1.0_f32), Complex::new(-0.5_f32, 0.0_f32)];
+/// let mut out = [Complex::new(0.0_f32, 0.0_f32); 2];
+/// li2_slice(&z, &mut out);
+/// assert!(out[0] == z[0].li2());
+/// ```
+pub fn li2_slice<T: Li2<T>>(x: &[T], out: &mut [T]) {
+    map_slice("li2_slice", x, out, |xi| xi.li2())
+}
+
+/// Writes the trilogarithms Li3(x) of all elements of `x` to `out`.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li3_slice<T: Li3<T>>(x: &[T], out: &mut [T]) {
+    map_slice("li3_slice", x, out, |xi| xi.li3())
+}
+
+/// Writes the 4th order polylogarithms Li4(x) of all elements of `x`
+/// to `out`.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li4_slice<T: Li4<T>>(x: &[T], out: &mut [T]) {
+    map_slice("li4_slice", x, out, |xi| xi.li4())
+}
+
+/// Writes the 5th order polylogarithms Li5(z) of all elements of `z`
+/// to `out`.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li5_slice<T: Li5<T>>(z: &[T], out: &mut [T]) {
+    map_slice("li5_slice", z, out, |zi| zi.li5())
+}
+
+/// Writes the 6th order polylogarithms Li6(z) of all elements of `z`
+/// to `out`.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li6_slice<T: Li6<T>>(z: &[T], out: &mut [T]) {
+    map_slice("li6_slice", z, out, |zi| zi.li6())
+}
+
+/// Writes the real n-th order polylogarithms Re[Li(n,x)] of all
+/// elements of `x` to `out` for a fixed integer order n.
+///
+/// The powers k^n of the series expansion and the harmonic number
+/// H(n-1) of the expansion around x = 1 are calculated only once.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+///
+/// # Example:
+/// ```
+/// use polylog::Li;
+/// use polylog::batch::li_slice;
+///
+/// let x = [-3.0, 0.5, 0.9];
+/// let mut out = [0.0; 3];
+/// li_slice(7, &x, &mut out);
+/// assert!(out[2] == 0.9_f64.li(7));
+/// ```
+pub fn li_slice(n: i32, x: &[f64], out: &mut [f64]) {
+    let t = Table::new(n);
+    map_slice("li_slice", x, out, |xi| rli_table(&t, *xi))
+}
+
+/// Writes the complex n-th order polylogarithms Li(n,z) of all
+/// elements of `z` to `out` for a fixed integer order n.
+///
+/// The powers k^n of the series expansion and the harmonic number
+/// H(n-1) of the expansion around z = 1 are calculated only once.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li_slice_complex(n: i32, z: &[Complex<f64>], out: &mut [Complex<f64>]) {
+    let t = Table::new(n);
+    map_slice("li_slice_complex", z, out, |zi| cli_table(&t, *zi))
+}
+
+/// Returns an iterator over the real n-th order polylogarithms
+/// Re[Li(n,x)] of the elements x of `iter` for a fixed integer order
+/// n, where the order-dependent coefficients are calculated only
+/// once.
+///
+/// # Example:
+/// ```
+/// use polylog::Li;
+/// use polylog::batch::li_iter;
+///
+/// let li: Vec<f64> = li_iter(8, (1..10).map(|k| 0.1*(k as f64))).collect();
+/// assert!(li[4] == 0.5_f64.li(8));
+/// ```
+pub fn li_iter<I: IntoIterator<Item = f64>>(n: i32, iter: I) -> impl Iterator<Item = f64> {
+    let t = Table::new(n);
+    iter.into_iter().map(move |x| rli_table(&t, x))
+}
+
+/// Returns an iterator over the complex n-th order polylogarithms
+/// Li(n,z) of the elements z of `iter` for a fixed integer order n,
+/// where the order-dependent coefficients are calculated only once.
+pub fn li_iter_complex<I: IntoIterator<Item = Complex<f64>>>(n: i32, iter: I) -> impl Iterator<Item = Complex<f64>> {
+    let t = Table::new(n);
+    iter.into_iter().map(move |z| cli_table(&t, z))
+}
@@ -0,0 +1,175 @@
+//! Bose-Einstein functions
+//!
+//! g_s(z) = 1/Gamma(s) int(t=0:Inf, t^(s-1)/(e^t/z - 1)) = Li(s,z)
+//!
+//! for integer and half-integer s and real z <= 1, and their inverse
+//! with respect to the fugacity z.
+//!
+//! # Example:
+//! ```
+//! use polylog::bose_einstein::{bose_einstein, bose_einstein_inv};
+//!
+//! let g = bose_einstein(1.5, 0.5);
+//! assert!((g - 0.62483702081991385_f64).abs() < 4.0*std::f64::EPSILON);
+//! assert!((bose_einstein_inv(1.5, g) - 0.5).abs() < 4.0*std::f64::EPSILON);
+//! ```
+
+use crate::Li;
+use crate::fermi_dirac::fermi_dirac;
+
+/// Returns the Bose-Einstein function
+///
+/// g_s(z) = Li(s,z) = sum(k=1:Inf, z^k/k^s)
+///
+/// for integer and half-integer s and real z <= 1.  At the branch
+/// point z = 1 the function is finite, g_s(1) = zeta(s), for s > 1
+/// and infinite for s <= 1.  Returns NaN for z > 1.
+///
+/// For integer s, g_s(z) is calculated from the real polylogarithm
+/// `Li`.  For half-integer s and 0 < z < 1 the duplication formula
+///
+/// g_s(z) = 2^(1-s) g_s(z^2) + F_(s-1)(ln(z))
+///
+/// is applied until z <= 1/2, where the series is used.  Here
+/// F_j(eta) = -Li(j+1,-e^eta) is the complete Fermi-Dirac integral.
+///
+/// # Panics
+///
+/// Panics if s is neither an integer nor a half-integer.
+pub fn bose_einstein(s: f64, z: f64) -> f64 {
+    if !(2.0*s).is_finite() || (2.0*s).fract() != 0.0 {
+        panic!("bose_einstein not implemented for non-integer and non-half-integer s (given value: s = {})", s);
+    }
+
+    if z.is_nan() || z > 1.0 {
+        f64::NAN
+    } else if z == 1.0 {
+        if s <= 1.0 {
+            f64::INFINITY
+        } else if s.fract() == 0.0 {
+            z.li(s as i32)
+        } else {
+            fermi_dirac(s - 1.0, 0.0)/(1.0 - 2.0_f64.powf(1.0 - s))
+        }
+    } else if s.fract() == 0.0 {
+        z.li(s as i32)
+    } else if z == 0.0 {
+        0.0
+    } else if z < 0.0 {
+        -fermi_dirac(s - 1.0, (-z).ln())
+    } else if z <= 0.5 {
+        be_series(s, z)
+    } else {
+        be_half(s, z.ln())
+    }
+}
+
+/// Returns the fugacity z <= 1 for which the Bose-Einstein function
+/// takes the value g >= 0,
+///
+/// g_s(z) = g,
+///
+/// for integer and half-integer s.  For s > 1 the function g_s(z) is
+/// bounded by zeta(s) on [0,1] and NaN is returned for g > zeta(s).
+/// Returns NaN for g < 0.
+///
+/// The equation is solved by Newton's method in mu = ln(z), using the
+/// derivative
+///
+/// d/dmu g_s(e^mu) = z d/dz g_s(z) = g_(s-1)(z).
+///
+/// The Newton iteration is safeguarded by bisection, because g_s(e^mu)
+/// is not analytic at the branch point mu = 0, where its derivative
+/// diverges for s < 2.  For small z a final Newton step is performed
+/// in z.
+///
+/// # Panics
+///
+/// Panics if s is neither an integer nor a half-integer.
+pub fn bose_einstein_inv(s: f64, g: f64) -> f64 {
+    let gmax = bose_einstein(s, 1.0);
+
+    if g.is_nan() || g < 0.0 || g > gmax {
+        f64::NAN
+    } else if g == 0.0 {
+        0.0
+    } else if g == gmax {
+        1.0
+    } else {
+        let mu = be_inv(s, g);
+        let z = mu.exp();
+        if mu < -1.0 {
+            // final Newton step in z, to avoid the amplification of
+            // the error of mu by the exponential function
+            z - (bose_einstein(s, z) - g)/bose_einstein(s - 1.0, z)*z
+        } else {
+            z
+        }
+    }
+}
+
+/// returns g_s(e^mu) for half-integer s and mu < 0
+fn be_half(s: f64, mu: f64) -> f64 {
+    let fac = 2.0_f64.powf(1.0 - s);
+    let mut mu = mu;
+    let mut p = 1.0; // collects 2^(m(1-s))
+    let mut sum = 0.0;
+
+    // duplication formula, doubling mu until mu <= -ln(2)
+    while mu > -std::f64::consts::LN_2 {
+        sum += p*fermi_dirac(s - 1.0, mu);
+        p *= fac;
+        mu *= 2.0;
+    }
+
+    sum + p*be_series(s, mu.exp())
+}
+
+/// returns g_s(z) = sum(k=1:Inf, z^k/k^s) for 0 < z <= 1/2
+fn be_series(s: f64, z: f64) -> f64 {
+    let mut zk = 1.0; // collects z^k
+    let mut sum = 0.0;
+
+    for k in 1..i32::MAX {
+        zk *= z;
+        let old_sum = sum;
+        sum += zk/(k as f64).powf(s);
+        if sum == old_sum { break; }
+    }
+
+    sum
+}
+
+/// returns mu = ln(z) with g_s(z) = g for 0 < g < g_s(1)
+fn be_inv(s: f64, g: f64) -> f64 {
+    let f = |mu: f64| bose_einstein(s, mu.exp()) - g;
+
+    // bracket [lo,hi] of the root, using g_s(z) > z for 0 < z < 1
+    let mut hi = if g < 1.0 { g.ln() } else { 0.0 };
+    let mut lo = hi.min(-1.0);
+    while f(lo) > 0.0 {
+        hi = lo;
+        lo *= 2.0;
+    }
+
+    // safeguarded Newton iteration
+    let mut mu = 0.5*(lo + hi);
+
+    for _ in 0..200 {
+        let y = f(mu);
+        if y == 0.0 { break; }
+        if y < 0.0 { lo = mu; } else { hi = mu; }
+
+        let d = bose_einstein(s - 1.0, mu.exp());
+        let mut mu_new = mu - y/d;
+        if !(mu_new > lo && mu_new < hi) {
+            mu_new = 0.5*(lo + hi);
+        }
+
+        let old_mu = mu;
+        mu = mu_new;
+        if (mu - old_mu).abs() <= f64::EPSILON*mu.abs() { break; }
+    }
+
+    mu
+}
@@ -36,6 +36,25 @@ impl<T: Float + Pi<T>> CLn<Complex<T>> for Complex<T> {
     }
 }
 
+/// returns e^z - 1 without cancellation for small |z|
+pub fn expm1(z: Complex<f64>) -> Complex<f64> {
+    let s = (0.5*z.im).sin();
+    Complex::new(z.re.exp_m1()*z.im.cos() - 2.0*s*s, z.re.exp()*z.im.sin())
+}
+
+/// returns ln(1 + z) without cancellation for small |z|, where the
+/// imaginary part is treated as in `cln()`
+pub fn ln_1p(z: Complex<f64>) -> Complex<f64> {
+    let re = 0.5*(z.re*(2.0 + z.re) + z.im*z.im).ln_1p();
+    if z.im == 0.0 && z.re < -1.0 {
+        Complex::new(re, std::f64::consts::PI)
+    } else if z.im == 0.0 {
+        Complex::new(re, 0.0)
+    } else {
+        Complex::new(re, z.im.atan2(1.0 + z.re))
+    }
+}
+
 #[test]
 fn test_cln() {
     // test positive zero
@@ -66,3 +85,25 @@ fn test_cln() {
     assert!(Complex::new( rzero64, rzero64).cln() == Complex::new(std::f64::NEG_INFINITY, 0.0));
     assert!(Complex::new(-rzero64, rzero64).cln() == Complex::new(std::f64::NEG_INFINITY, std::f64::consts::PI));
 }
+
+#[test]
+fn test_expm1() {
+    let eps = 1e-15;
+    let z = Complex::new(1e-10, 2e-10);
+    let expected = Complex::new(9.99999999850000000001e-11, 2.0000000002000000000e-10);
+    assert!((expm1(z) - expected).norm() < eps*expected.norm());
+    let z = Complex::new(0.5, 2.0);
+    assert!((expm1(z) - (z.exp() - 1.0)).norm() < eps);
+}
+
+#[test]
+fn test_ln_1p() {
+    let eps = 1e-15;
+    let z = Complex::new(1e-10, 2e-10);
+    let expected = Complex::new(1.00000000015e-10, 1.99999999980e-10);
+    assert!((ln_1p(z) - expected).norm() < eps*expected.norm());
+    let z = Complex::new(0.5, -2.0);
+    assert!((ln_1p(z) - (1.0 + z).cln()).norm() < eps);
+    let z = Complex::new(-3.0, -0.0);
+    assert!((ln_1p(z) - (1.0 + z).cln()).norm() < eps);
+}
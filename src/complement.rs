@@ -0,0 +1,128 @@
+use num::complex::Complex;
+use crate::cln::{CLn, ln_1p};
+use crate::{Li, Li2, LiExp};
+
+/// Provides the polylogarithms of the complement 1 - d of a number d
+/// of type `T`, i.e. `d.li2_1m()` returns Li2(1-d).
+///
+/// For small |d| the difference 1 - d is not formed, such that the
+/// precision of d is retained.  Instead, the dilogarithm is calculated
+/// from the reflection formula
+///
+/// Li2(1-d) = zeta(2) - ln(d) ln(1-d) - Li2(d)
+///
+/// and the polylogarithms of higher order are calculated from the
+/// series expansion around z = 1 in terms of ln(1-d), see `LiExp`.
+pub trait LiComplement<T> {
+    fn li2_1m(&self) -> T;
+    fn li3_1m(&self) -> T;
+    fn li4_1m(&self) -> T;
+    fn li_1m(&self, n: i32) -> T;
+}
+
+impl LiComplement<f64> for f64 {
+    /// Returns the real dilogarithm Re[Li2(1-d)] of the complement of a
+    /// real number d of type `f64`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::LiComplement;
+    ///
+    /// assert!((1e-10_f64.li2_1m() - 1.6449340644456413_f64).abs() < 2.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li2_1m(&self) -> f64 {
+        let z2 = 1.6449340668482264;
+        let d = *self;
+
+        if d == 0.0 {
+            z2
+        } else if d.abs() < 0.5 {
+            z2 - d.abs().ln()*(-d).ln_1p() - d.li2()
+        } else {
+            (1.0 - d).li2()
+        }
+    }
+
+    /// Returns the real trilogarithm Re[Li3(1-d)] of the complement of
+    /// a real number d of type `f64`.
+    fn li3_1m(&self) -> f64 {
+        self.li_1m(3)
+    }
+
+    /// Returns the real fourth order polylogarithm Re[Li4(1-d)] of the
+    /// complement of a real number d of type `f64`.
+    fn li4_1m(&self) -> f64 {
+        self.li_1m(4)
+    }
+
+    /// Returns the real n-th order polylogarithm Re[Li(n,1-d)] of the
+    /// complement of a real number d of type `f64` for all integers
+    /// `n`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::LiComplement;
+    ///
+    /// assert!((1e-10_f64.li_1m(3) - 1.2020569029951009_f64).abs() < 2.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_1m(&self, n: i32) -> f64 {
+        let d = *self;
+
+        if d.abs() < 0.5 {
+            (-d).ln_1p().li_exp(n)
+        } else {
+            (1.0 - d).li(n)
+        }
+    }
+}
+
+impl LiComplement<Complex<f64>> for Complex<f64> {
+    /// Returns the complex dilogarithm Li2(1-d) of the complement of a
+    /// complex number d of type `Complex<f64>`.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::LiComplement;
+    ///
+    /// let d = Complex::new(1e-10_f64, 1e-10_f64);
+    /// assert!((d.li2_1m() - Complex::new(1.6449340644017589_f64, -2.2893879178580964e-9_f64)).norm() < 2.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li2_1m(&self) -> Complex<f64> {
+        let z2 = 1.6449340668482264;
+        let d = *self;
+
+        if d.re == 0.0 && d.im == 0.0 {
+            Complex::new(z2, 0.0)
+        } else if d.norm_sqr() < 0.25 {
+            z2 - d.cln()*ln_1p(-d) - d.li2()
+        } else {
+            (1.0 - d).li2()
+        }
+    }
+
+    /// Returns the complex trilogarithm Li3(1-d) of the complement of a
+    /// complex number d of type `Complex<f64>`.
+    fn li3_1m(&self) -> Complex<f64> {
+        self.li_1m(3)
+    }
+
+    /// Returns the complex fourth order polylogarithm Li4(1-d) of the
+    /// complement of a complex number d of type `Complex<f64>`.
+    fn li4_1m(&self) -> Complex<f64> {
+        self.li_1m(4)
+    }
+
+    /// Returns the complex n-th order polylogarithm Li(n,1-d) of the
+    /// complement of a complex number d of type `Complex<f64>` for all
+    /// integers `n`.
+    fn li_1m(&self, n: i32) -> Complex<f64> {
+        let d = *self;
+
+        if d.norm_sqr() < 0.25 {
+            ln_1p(-d).li_exp(n)
+        } else {
+            (1.0 - d).li(n)
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use num::complex::Complex;
+use crate::{Li, Li0, Li1, Li2, Li3, Li4, Li5, Li6};
+
+/// Provides the n-th order polylogarithm `polylog()` of a number of
+/// type `T` for an order n, which is known at compile time, i.e.
+/// `z.polylog::<N>()` returns Li(N,z).
+///
+/// The dispatch on the order happens at compile time: for the orders,
+/// for which a dedicated trait `Li0`, ..., `Li6` exists, the
+/// corresponding function is called directly, for all other orders the
+/// general algorithm of `Li` is used.  The special values and, for
+/// complex z, the real axis are treated as in `Li`, such that the
+/// results are identical to the ones of `Li`.
+///
+/// # Example:
+/// ```
+/// use polylog::{Li, LiConst};
+///
+/// fn sum<const N: i32>(x: &[f64]) -> f64 {
+///     x.iter().map(|x| x.polylog::<N>()).sum()
+/// }
+///
+/// assert!(sum::<2>(&[0.5]) == 0.5_f64.li(2));
+/// assert!(sum::<8>(&[0.5]) == 0.5_f64.li(8));
+/// ```
+pub trait LiConst<T> {
+    fn polylog<const N: i32>(&self) -> T;
+}
+
+impl LiConst<f64> for f64 {
+    /// Returns the real n-th order polylogarithm Re[Li(N,x)] of a real
+    /// number of type `f64` for all integers `N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Li3, LiConst};
+    ///
+    /// assert!(2.0_f64.polylog::<3>() == 2.0_f64.li3());
+    /// ```
+    fn polylog<const N: i32>(&self) -> f64 {
+        let x = *self;
+
+        if x == 0.0 || x == 1.0 || x == -1.0 || x.is_nan() {
+            return x.li(N);
+        }
+
+        match N {
+            0 => x.li0(),
+            1 => x.li1(),
+            2 => x.li2(),
+            3 => x.li3(),
+            4 => x.li4(),
+            _ => x.li(N),
+        }
+    }
+}
+
+impl LiConst<Complex<f64>> for Complex<f64> {
+    /// Returns the complex n-th order polylogarithm Li(N,z) of a
+    /// complex number of type `Complex<f64>` for all integers `N`.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiConst};
+    ///
+    /// let z = Complex::new(1.0_f64, 1.0_f64);
+    /// assert!(z.polylog::<10>() == z.li(10));
+    /// ```
+    fn polylog<const N: i32>(&self) -> Complex<f64> {
+        let z = *self;
+
+        if z.im == 0.0 || !z.is_finite() {
+            return z.li(N);
+        }
+
+        match N {
+            0 => z.li0(),
+            1 => z.li1(),
+            2 => z.li2(),
+            3 => z.li3(),
+            4 => z.li4(),
+            5 => z.li5(),
+            6 => z.li6(),
+            _ => z.li(N),
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Debye functions
+//!
+//! D_n(x) = n/x^n int(t=0:x, t^n/(e^t - 1))
+//!
+//! for integer n >= 1.
+//!
+//! # Example:
+//! ```
+//! use polylog::debye::debye;
+//!
+//! assert!((debye(3, 1.0) - 0.6744155640778147_f64).abs() < 2.0*std::f64::EPSILON);
+//! ```
+
+use crate::Li;
+use crate::li::zeta::zeta;
+use crate::numbers::bernoulli_f64;
+
+/// Returns the Debye function
+///
+/// D_n(x) = n/x^n int(t=0:x, t^n/(e^t - 1))
+///
+/// for integer n >= 1 and real x.
+///
+/// For small |x| the series
+///
+/// D_n(x) = 1 - n x/(2(n+1)) + n sum(k=1:Inf, B(2k) x^(2k)/((2k+n) (2k)!))
+///
+/// is used, where B(2k) are the Bernoulli numbers.  For intermediate
+/// x < n and n > 8 the series of positive terms
+///
+/// D_n(x) = n x sum(j=1:Inf, e^(-j x) sum(m=0:Inf, (j x)^m/((n+1) (n+2) ... (n+1+m))))
+///
+/// is used.  Otherwise D_n(x) is calculated from
+///
+/// D_n(x) = n/x^n (n! zeta(n+1) - sum(k=0:n, n!/(n-k)! x^(n-k) Li(k+1,e^(-x))))
+///
+/// where the terms involving e^(-x) are omitted for large x.  For
+/// x < 0 the relation D_n(x) = D_n(-x) - n x/(n+1) is used.
+///
+/// # Panics
+///
+/// Panics if n < 1.
+pub fn debye(n: i32, x: f64) -> f64 {
+    if n < 1 {
+        panic!("debye not implemented for n < 1 (given value: n = {})", n);
+    }
+
+    if x.is_nan() {
+        f64::NAN
+    } else if x < 0.0 {
+        debye(n, -x) - (n as f64)*x/((n + 1) as f64)
+    } else if x == 0.0 {
+        1.0
+    } else if x < debye_series_max(n) {
+        debye_series(n, x)
+    } else if n > 8 && x < (n as f64).min(700.0) {
+        debye_exp(n, x)
+    } else if x > 50.0 + 3.0*(n as f64) {
+        debye_asymptotic(n, x)
+    } else {
+        debye_li(n, x)
+    }
+}
+
+/// returns the upper bound of x for the series expansion of D_n(x),
+/// below which the formula in terms of Li(k,e^(-x)) suffers from
+/// cancellations
+fn debye_series_max(n: i32) -> f64 {
+    if n > 8 { 3.0 } else { 1.0 + 0.5*(n as f64) }
+}
+
+/// returns D_n(x) from the series expansion for |x| < 2 pi
+fn debye_series(n: i32, x: f64) -> f64 {
+    let nf = n as f64;
+    let x2 = x*x;
+    let mut p = 1.0; // collects x^(2k)/(2k)!
+    let mut sum = 0.0;
+
+    for k in 1..130 {
+        let k2 = (2*k) as f64;
+        p *= x2/((k2 - 1.0)*k2);
+        let old_sum = sum;
+        sum += bernoulli_f64(2*k as u32)*p/(k2 + nf);
+        if sum == old_sum { break; }
+    }
+
+    1.0 - nf*x/(2.0*(nf + 1.0)) + nf*sum
+}
+
+/// returns D_n(x) from the series of positive terms for 0 < x < 700
+///
+/// D_n(x) = n x sum(j=1:Inf, e^(-j x) sum(m=0:Inf, (j x)^m/((n+1) (n+2) ... (n+1+m))))
+///
+/// where the inner sum is related to the incomplete gamma function
+/// of int(t=0:x, t^n e^(-j t)).  The terms with j x > 700 are
+/// negligible.
+fn debye_exp(n: i32, x: f64) -> f64 {
+    let nf = n as f64;
+    let mut sum = 0.0;
+
+    for j in 1..i32::MAX {
+        let y = (j as f64)*x;
+        if y > 700.0 { break; }
+
+        let mut p = 1.0/(nf + 1.0); // collects y^m/((n+1) ... (n+1+m))
+        let mut inner = p;
+
+        for m in 1..i32::MAX {
+            p *= y/(nf + 1.0 + (m as f64));
+            let old_inner = inner;
+            inner += p;
+            if inner == old_inner { break; }
+        }
+
+        let old_sum = sum;
+        sum += (-y).exp()*inner;
+        if sum == old_sum { break; }
+    }
+
+    nf*x*sum
+}
+
+/// returns D_n(x) = n/x^n (n! zeta(n+1) - sum(k=0:n, n!/(n-k)! x^(n-k) Li(k+1,e^(-x))))
+fn debye_li(n: i32, x: f64) -> f64 {
+    let e = (-x).exp();
+    let mut p = 1.0; // collects n!/(n-k)!/x^k
+    let mut sum = 0.0;
+
+    for k in 0..=n {
+        sum += p*e.li(k + 1);
+        p *= ((n - k) as f64)/x;
+    }
+
+    (n as f64)*(n_fac_over_xn(n, x)*zeta(n + 1) - sum)
+}
+
+/// returns D_n(x) = n n! zeta(n+1)/x^n for large x, where the terms
+/// of the order e^(-x) are negligible
+fn debye_asymptotic(n: i32, x: f64) -> f64 {
+    (n as f64)*n_fac_over_xn(n, x)*zeta(n + 1)
+}
+
+/// returns n!/x^n without intermediate overflow
+fn n_fac_over_xn(n: i32, x: f64) -> f64 {
+    (1..=n).fold(1.0, |p, k| p*(k as f64)/x)
+}
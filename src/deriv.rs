@@ -0,0 +1,194 @@
+use num::complex::Complex;
+use crate::Li;
+use crate::cln::{CLn, expm1};
+use crate::li::fac::stirling_ratio;
+use crate::numbers::stirling1_f64;
+use crate::quad::{integrate_pole, pole_height};
+
+/// Provides the k-th derivative `li_deriv()` of the n-th order
+/// polylogarithm with respect to its argument z of type `T`, i.e.
+/// `z.li_deriv(n, k)` returns d^k/dz^k Li(n,z).
+///
+/// Near the origin the power series
+///
+/// d^k/dz^k Li(n,z) = sum(j=0:Inf, (j+1) (j+2) ... (j+k) z^j/(j+k)^n)
+///
+/// is used for |z| <= 1/2, for |z| <= 9/10 if n > k and for |z| <= 1
+/// if n > k + 1 and the coefficients decrease fast enough.  For k = 1
+/// the relation d/dz Li(n,z) = Li(n-1,z)/z is used.  Otherwise, for
+/// n >= 1 the derivative is calculated from the integral
+/// representation
+///
+/// d^k/dz^k Li(n,z) = k!/(n-1)! int(t=0:Inf, t^(n-1) e^(-k t)/(1 - z e^(-t))^(k+1))
+///
+/// where the path of integration is deformed around the pole at
+/// t = ln(z) for z close to the branch cut [1,Inf).  For n = 0 the
+/// derivative k!/(1 - z)^(k+1) is used and for n < 0 the derivative
+/// of the rational function Li(n,z) is calculated from
+///
+/// d^k/dz^k Li(n,z) = z^(-k) sum(j=1:k, s(k,j) Li(n-j,z))
+///
+/// where s(k,j) are the signed Stirling numbers of the first kind.
+/// Unlike this sum, the integral does not suffer from cancellations
+/// for n >> 1, where all Li(n-j,z) ~ z.
+pub trait LiDeriv<T> {
+    fn li_deriv(&self, n: i32, k: u32) -> T;
+}
+
+impl LiDeriv<f64> for f64 {
+    /// Returns the k-th derivative of the real n-th order
+    /// polylogarithm Re[Li(n,x)] with respect to a real number x of
+    /// type `f64` for all integers `n`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::LiDeriv;
+    ///
+    /// // d/dx Li(2,x) = -ln(1 - x)/x
+    /// assert!((0.5_f64.li_deriv(2, 1) - 2.0*std::f64::consts::LN_2).abs() < 1e-15_f64);
+    /// ```
+    fn li_deriv(&self, n: i32, k: u32) -> f64 {
+        let x = *self;
+
+        if k == 0 {
+            x.li(n)
+        } else if x.is_nan() {
+            f64::NAN
+        } else if is_series(n, k, x.abs()) {
+            deriv_series(n, k, x)
+        } else if k == 1 {
+            x.li(n - 1)/x
+        } else {
+            Complex::new(x, 0.0).li_deriv(n, k).re
+        }
+    }
+}
+
+impl LiDeriv<Complex<f64>> for Complex<f64> {
+    /// Returns the k-th derivative of the complex n-th order
+    /// polylogarithm Li(n,z) with respect to a complex number z of
+    /// type `Complex<f64>` for all integers `n`.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiDeriv};
+    ///
+    /// // d/dz Li(n,z) = Li(n-1,z)/z
+    /// let z = Complex::new(1.0_f64, 1.0_f64);
+    /// assert!((z.li_deriv(5, 1) - z.li(4)/z).norm() < 2.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_deriv(&self, n: i32, k: u32) -> Complex<f64> {
+        let z = *self;
+
+        if k == 0 {
+            z.li(n)
+        } else if z.is_nan() {
+            Complex::new(f64::NAN, f64::NAN)
+        } else if is_series(n, k, z.norm()) {
+            deriv_series_complex(n, k, z)
+        } else if k == 1 {
+            z.li(n - 1)/z
+        } else if z.is_infinite() {
+            Complex::new(0.0, 0.0)
+        } else if z == Complex::new(1.0, 0.0) && i64::from(n) <= i64::from(k) + 1 {
+            Complex::new(f64::INFINITY, 0.0)
+        } else if n >= 1 {
+            deriv_integral(n, k, z)
+        } else if n == 0 {
+            fac(k)/(1.0 - z).powi(k as i32 + 1)
+        } else {
+            let sum = (1..=k).fold(Complex::new(0.0, 0.0), |sum, j| sum + stirling1_f64(k, j)*z.li(n - j as i32));
+            sum*z.inv().powi(k as i32)
+        }
+    }
+}
+
+/// returns true if the power series is used for |z| = r
+fn is_series(n: i32, k: u32, r: f64) -> bool {
+    let m = i64::from(n) - i64::from(k);
+    r <= 0.5 || (r <= 0.9 && m > 0) || (r <= 1.0 && m > 1 && series_terms(m, k) <= 100.0)
+}
+
+/// returns an estimate of the number of terms of the power series
+/// for |z| = 1, derived from c_j/c_0 < e^k (k/(j+k))^(n-k) < eps
+fn series_terms(m: i64, k: u32) -> f64 {
+    let k = f64::from(k);
+    k*(((37.0 + k)/(m as f64)).exp() - 1.0)
+}
+
+/// returns k!
+fn fac(k: u32) -> f64 {
+    (1..=k).fold(1.0, |p, i| p*f64::from(i))
+}
+
+/// returns the coefficients c_j = (j+1) (j+2) ... (j+k)/(j+k)^n of the
+/// power series of d^k/dz^k Li(n,z), given c_(j-1)
+fn next_coeff(n: i32, k: u32, j: u32, c: f64) -> f64 {
+    let jk = (j + k) as f64;
+    c*jk/(j as f64)*((jk - 1.0)/jk).powi(n)
+}
+
+/// returns d^k/dx^k Li(n,x) = sum(j=0:Inf, c_j x^j) for |x| < 1
+fn deriv_series(n: i32, k: u32, x: f64) -> f64 {
+    let mut c = fac(k)/(k as f64).powi(n);
+    let mut xj = 1.0; // collects x^j
+    let mut sum = c;
+
+    for j in 1..u32::MAX {
+        c = next_coeff(n, k, j, c);
+        xj *= x;
+        let old_sum = sum;
+        sum += c*xj;
+        if sum == old_sum { break; }
+    }
+
+    sum
+}
+
+/// returns d^k/dz^k Li(n,z) = sum(j=0:Inf, c_j z^j) for |z| < 1
+fn deriv_series_complex(n: i32, k: u32, z: Complex<f64>) -> Complex<f64> {
+    let mut c = fac(k)/(k as f64).powi(n);
+    let mut zj = Complex::new(1.0, 0.0); // collects z^j
+    let mut sum = Complex::new(c, 0.0);
+
+    for j in 1..u32::MAX {
+        c = next_coeff(n, k, j, c);
+        zj *= z;
+        let old_sum = sum;
+        sum += c*zj;
+        if sum == old_sum { break; }
+    }
+
+    sum
+}
+
+/// returns d^k/dz^k Li(n,z) for n >= 1 from the integral
+/// representation, see `LiDeriv`
+///
+/// The integrand is divided by ts^(n-1) e^(-(n-1)), where
+/// ts = (n-1)/k is the position of the maximum of t^(n-1) e^(-k t).
+/// If the pole at t = ln(z) is close to the positive real axis, the
+/// path passes the pole above, such that the result corresponds to
+/// Li(n,z) below the branch cut for Im(z) = 0.
+fn deriv_integral(n: i32, k: u32, z: Complex<f64>) -> Complex<f64> {
+    let mu = z.cln();
+    let m = n - 1;
+    let ts = f64::from(m)/f64::from(k);
+    let sigma = f64::from(m).sqrt().max(1.0)/f64::from(k);
+    let c = if mu.re > 0.0 { pole_height(f64::from(m), f64::from(k + 1), mu.re) } else { 0.0 };
+
+    let f = |t: Complex<f64>| {
+        let e = if m == 0 {
+            -f64::from(k)*t
+        } else {
+            let v = t/ts;
+            f64::from(m)*(v.ln() - v + 1.0)
+        };
+        e.exp()/(-expm1(mu - t)).powi(k as i32 + 1)
+    };
+
+    let sum = integrate_pole(f, 0.0, mu, c, sigma.min(16.0), (ts + 10.0*sigma).max(mu.re + 1.0));
+
+    sum*(fac(k)*stirling_ratio(m)*f64::from(k).powi(-m))
+}
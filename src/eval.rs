@@ -0,0 +1,71 @@
+use num::complex::Complex;
+use crate::li::cli::cli_table;
+use crate::li::rli::rli_table;
+use crate::li::table::{Coeffs, Table};
+
+/// Evaluator of the n-th order polylogarithm Li(n,z) for a fixed
+/// integer order n.
+///
+/// The order-dependent coefficients of the series expansions, i.e.
+/// the powers k^n, the harmonic number H(n-1) and the zeta values
+/// zeta(n-j), are calculated once in `Polylog::new(n)` and are reused
+/// in every call of `eval()`.  The results are identical to the ones
+/// of the `Li` trait.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::{Li, Polylog, PolylogEval};
+///
+/// let li10 = Polylog::new(10);
+/// let z = Complex::new(1.0_f64, 1.0_f64);
+///
+/// assert!(li10.eval(0.5) == 0.5_f64.li(10));
+/// assert!(li10.eval(z) == z.li(10));
+/// ```
+pub struct Polylog {
+    table: Table,
+}
+
+impl Polylog {
+    /// Returns an evaluator of the n-th order polylogarithm for the
+    /// integer order `n`.
+    pub fn new(n: i32) -> Polylog {
+        Polylog { table: Table::new(n) }
+    }
+
+    /// Returns the order n.
+    pub fn order(&self) -> i32 {
+        self.table.order()
+    }
+}
+
+/// Provides the evaluation `eval()` of the polylogarithm of fixed
+/// order for an argument of type `T`.
+pub trait PolylogEval<T> {
+    fn eval(&self, z: T) -> T;
+}
+
+impl PolylogEval<f64> for Polylog {
+    /// Returns the real n-th order polylogarithm Re[Li(n,x)] of a real
+    /// number of type `f64`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Polylog, PolylogEval};
+    ///
+    /// let li7 = Polylog::new(7);
+    /// assert!((li7.eval(1.0) - 1.0083492773819228_f64).abs() < std::f64::EPSILON);
+    /// ```
+    fn eval(&self, x: f64) -> f64 {
+        rli_table(&self.table, x)
+    }
+}
+
+impl PolylogEval<Complex<f64>> for Polylog {
+    /// Returns the complex n-th order polylogarithm Li(n,z) of a
+    /// complex number of type `Complex<f64>`.
+    fn eval(&self, z: Complex<f64>) -> Complex<f64> {
+        cli_table(&self.table, z)
+    }
+}
@@ -0,0 +1,427 @@
+//! Series expansions of polylogarithms
+//!
+//! The Taylor expansion of Li(n,z) around an arbitrary point z0 != 1,
+//!
+//! Li(n,z0+h) = sum(k=0:Inf, c_k h^k),
+//!
+//! the expansions around the branch point z = 1 in h = z - 1 and in
+//! mu = ln(z), which contain a logarithm for n >= 1 and a pole for
+//! n <= 0, and the asymptotic expansion for large |z| in powers of
+//! ln(-z).
+//!
+//! # Example:
+//! ```
+//! use num::complex::Complex;
+//! use polylog::Li;
+//! use polylog::expansions::li_taylor;
+//!
+//! let z0 = Complex::new(0.5, 0.5);
+//! let h = Complex::new(0.01, -0.02);
+//! let c = li_taylor(3, z0, 20);
+//! let sum = c.iter().rev().fold(Complex::new(0.0, 0.0), |s, ck| s*h + ck);
+//! assert!((sum - (z0 + h).li(3)).norm() < 4.0*std::f64::EPSILON);
+//! ```
+
+use num::complex::Complex;
+use crate::cln::CLn;
+use crate::Li;
+use crate::li::eta::neg_eta;
+use crate::li::fac::{fac, inv_fac};
+use crate::li::harmonic::harmonic;
+use crate::li::zeta::zeta;
+
+/// Expansion of a function f(x) around x = 0 of the form
+///
+/// f(x) = sum(k=1:p, pole[k-1] x^(-k)) + sum(k=0:m, regular[k] x^k)
+///        + ln(-x) sum(k=0:m, log[k] x^k)
+///
+/// with real coefficients, where ln(-x) is taken on the principal
+/// branch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogSeries {
+    /// coefficients of the pole terms x^(-1), ..., x^(-p)
+    pub pole: Vec<f64>,
+    /// coefficients of x^0, ..., x^m
+    pub regular: Vec<f64>,
+    /// coefficients of ln(-x) x^0, ..., ln(-x) x^m
+    pub log: Vec<f64>,
+}
+
+impl LogSeries {
+    /// Returns the truncated expansion at the complex point x.
+    ///
+    /// For x = 0 the limit is returned, which is infinite if the
+    /// expansion has a pole or a term proportional to ln(-x).
+    pub fn eval(&self, x: Complex<f64>) -> Complex<f64> {
+        let horner = |c: &[f64]| c.iter().rev().fold(Complex::new(0.0, 0.0), |s, ck| s*x + ck);
+
+        if x.re == 0.0 && x.im == 0.0 {
+            let is_inf = self.pole.iter().any(|&c| c != 0.0) || self.log.first().map_or(false, |&c| c != 0.0);
+            if is_inf {
+                Complex::new(f64::INFINITY, 0.0)
+            } else {
+                Complex::new(self.regular.first().cloned().unwrap_or(0.0), 0.0)
+            }
+        } else {
+            let xi = x.inv();
+            let pole = self.pole.iter().rev().fold(Complex::new(0.0, 0.0), |s, ck| (s + ck)*xi);
+            pole + horner(&self.regular) + (-x).cln()*horner(&self.log)
+        }
+    }
+}
+
+/// Returns the Taylor coefficients [c_0, ..., c_order] of the
+/// expansion
+///
+/// Li(n,z0+h) = sum(k=0:Inf, c_k h^k)
+///
+/// for all integers n and complex z0 != 1.  The series converges for
+/// |h| < |1 - z0|.  For real z0 > 1 the expansion of the continuation
+/// of Li(n,z) from below the branch cut is returned, consistent with
+/// `Li`.  For z0 = 1, where Li(n,z) is not analytic, a vector of NaN
+/// is returned, see `li_taylor_unity()` instead.
+///
+/// The coefficients are calculated from the recursion
+///
+/// z0 (k+1) c_(n,k+1) + k c_(n,k) = c_(n-1,k)
+///
+/// which follows from z d/dz Li(n,z) = Li(n-1,z), starting from the
+/// closed form of Li(1,z) for n >= 1 and of Li(0,z) for n <= 0, and
+/// c_(n,0) = Li(n,z0).  For n >= 2 the recursion is run forward if
+/// |z0| >= |1 - z0| or the order is low.  Otherwise it is run backward,
+/// starting at a higher order, since in forward direction rounding
+/// errors grow like (|1 - z0|/|z0|)^k.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::expansions::li_taylor;
+///
+/// // Li(1,z) = -ln(1-z)
+/// let c = li_taylor(1, Complex::new(-1.0, 0.0), 3);
+/// assert!((c[1] - 0.5).norm() < std::f64::EPSILON);
+/// assert!((c[2] - 0.125).norm() < std::f64::EPSILON);
+/// ```
+pub fn li_taylor(n: i32, z0: Complex<f64>, order: u32) -> Vec<Complex<f64>> {
+    let len = order as usize + 1;
+
+    if z0.is_nan() || (z0.re == 1.0 && z0.im == 0.0) {
+        return vec![Complex::new(f64::NAN, f64::NAN); len];
+    }
+
+    if z0.re == 0.0 && z0.im == 0.0 {
+        // Li(n,h) = sum(k=1:Inf, h^k/k^n)
+        return (0..len).map(|k| {
+            if k == 0 { Complex::new(0.0, 0.0) } else { Complex::new((k as f64).powi(-n), 0.0) }
+        }).collect();
+    }
+
+    let one = Complex::new(1.0, 0.0);
+    let w = (one - z0).inv();
+
+    if n <= 0 {
+        // Li(0,z0+h) = z0/(1-z0) + sum(k=1:Inf, h^k/(1-z0)^(k+1))
+        let mut c: Vec<Complex<f64>> = Vec::with_capacity(len + (-n) as usize);
+        let mut wk = w; // collects 1/(1-z0)^(k+1)
+        c.push(z0*w);
+        for _ in 1..(len + (-n) as usize) {
+            wk *= w;
+            c.push(wk);
+        }
+
+        // c_(n-1,k) = z0 (k+1) c_(n,k+1) + k c_(n,k)
+        for _ in n..0 {
+            c = (0..(c.len() - 1)).map(|k| {
+                z0*((k + 1) as f64)*c[k + 1] + (k as f64)*c[k]
+            }).collect();
+        }
+
+        return c;
+    }
+
+    // the recursion amplifies errors by |1-z0|/|z0| per order in
+    // forward direction and by |z0|/|1-z0| in backward direction
+    let r = z0.norm()*w.norm();
+    let is_forward = r >= 1.0 || (order as f64)*(-r.ln()) <= 16.0_f64.ln();
+    let m = if is_forward {
+        len - 1
+    } else {
+        len - 1 + 10 + (f64::EPSILON.ln()/r.ln()).ceil() as usize
+    };
+
+    // Li(1,z0+h) = -ln(1-z0) + sum(k=1:Inf, h^k/(k (1-z0)^k))
+    let mut c: Vec<Complex<f64>> = Vec::with_capacity(m + 1);
+    let mut wk = one; // collects 1/(1-z0)^k
+    c.push(z0.li(1));
+    for k in 1..=m {
+        wk *= w;
+        c.push(wk/(k as f64));
+    }
+
+    for j in 2..=n {
+        let mut a = vec![Complex::new(0.0, 0.0); m + 1];
+        a[0] = z0.li(j);
+
+        if is_forward {
+            for k in 0..m {
+                a[k + 1] = (c[k] - (k as f64)*a[k])/(z0*((k + 1) as f64));
+            }
+        } else {
+            // a_k ~ c_k (1-z0)/k for k -> Inf
+            a[m] = c[m]*(one - z0)/(m as f64);
+            for k in (1..m).rev() {
+                a[k] = (c[k] - z0*((k + 1) as f64)*a[k + 1])/(k as f64);
+            }
+        }
+
+        c = a;
+    }
+
+    c.truncate(len);
+    c
+}
+
+/// Returns the expansion of Li(n,z) around the branch point z = 1 in
+/// h = z - 1,
+///
+/// Li(n,1+h) = sum(k=1:(1-n), p_k h^(-k)) + sum(k=0:order, (r_k + l_k ln(-h)) h^k)
+///
+/// for all integers n, where the pole terms p_k are present for
+/// n <= 0 and the logarithmic terms l_k for n >= 1.  The expansion
+/// converges for |h| < 1.  For real h > 0 the principal branch
+/// ln(-h) = ln(h) + i pi reproduces the value of `Li` for real z > 1.
+///
+/// The coefficients are obtained from Li(1,1+h) = -ln(-h) by
+/// repeated integration,
+///
+/// Li(n+1,1+h) = zeta(n+1) + int(t=0:h, Li(n,1+t)/(1+t))
+///
+/// for n >= 1, and from Li(0,1+h) = -1/h - 1 by repeated application
+/// of (1+h) d/dh for n < 0.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::Li;
+/// use polylog::expansions::li_taylor_unity;
+///
+/// let s = li_taylor_unity(2, 20);
+/// let h = Complex::new(-0.01, 0.02);
+/// assert!((s.eval(h) - (1.0 + h).li(2)).norm() < 4.0*std::f64::EPSILON);
+/// ```
+pub fn li_taylor_unity(n: i32, order: u32) -> LogSeries {
+    let len = order as usize + 1;
+
+    if n <= 0 {
+        // Laurent coefficients c[i] of h^(i-p) of Li(0,1+h) = -1/h - 1
+        let mut p = 1;
+        let mut c = vec![0.0; p + len + (-n) as usize];
+        c[0] = -1.0;
+        c[1] = -1.0;
+
+        // c_(n-1,j) = (j+1) c_(n,j+1) + j c_(n,j)
+        for _ in n..0 {
+            let coeff = |i: usize| if i < c.len() { c[i] } else { 0.0 };
+            c = (0..c.len()).map(|i| {
+                // power j = i - (p+1) of the result
+                let j = i as f64 - (p + 1) as f64;
+                let cj = if i == 0 { 0.0 } else { coeff(i - 1) };
+                (j + 1.0)*coeff(i) + j*cj
+            }).collect();
+            p += 1;
+        }
+
+        let mut pole = c[..p].to_vec();
+        pole.reverse();
+
+        LogSeries { pole, regular: c[p..(p + len)].to_vec(), log: vec![0.0; len] }
+    } else {
+        // Li(1,1+h) = -ln(-h)
+        let mut regular = vec![0.0; len];
+        let mut log = vec![0.0; len];
+        log[0] = -1.0;
+
+        for j in 2..=n {
+            // divide by (1+h)
+            for k in 1..len {
+                regular[k] -= regular[k - 1];
+                log[k] -= log[k - 1];
+            }
+
+            // int(t=0:h, t^k ln(-t)) = h^(k+1) (ln(-h)/(k+1) - 1/(k+1)^2)
+            for k in (0..(len - 1)).rev() {
+                let k1 = (k + 1) as f64;
+                regular[k + 1] = regular[k]/k1 - log[k]/(k1*k1);
+                log[k + 1] = log[k]/k1;
+            }
+            regular[0] = zeta(j);
+            log[0] = 0.0;
+        }
+
+        LogSeries { pole: vec![], regular, log }
+    }
+}
+
+/// Returns the expansion of Li(n,e^mu) around mu = 0,
+///
+/// Li(n,e^mu) = sum(k=0:order, zeta(n-k) mu^k/k!)
+///              + mu^(n-1)/(n-1)! (H(n-1) - ln(-mu))
+///
+/// for n >= 1, where the term k = n-1 of the sum is omitted and
+/// H(n) = sum(k=1:n, 1/k) is the n-th harmonic number, and
+///
+/// Li(n,e^mu) = (-n)! (-mu)^(n-1) + sum(k=0:order, zeta(n-k) mu^k/k!)
+///
+/// for n <= 0.  The expansion converges for |mu| < 2 pi.  The
+/// coefficients of the regular part are returned in `regular`, the
+/// coefficient of mu^(n-1) ln(-mu) is the only non-zero element of
+/// `log` and the coefficient of mu^(n-1) for n <= 0 is the only
+/// non-zero element of `pole`.  For real mu > 0 the principal branch
+/// ln(-mu) = ln(mu) + i pi reproduces the value of `Li` for real z > 1.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::LiExp;
+/// use polylog::expansions::li_exp_unity;
+///
+/// let s = li_exp_unity(3, 30);
+/// assert!((s.log[2] + 0.5).abs() < std::f64::EPSILON);
+///
+/// let mu = Complex::new(0.1, -0.2);
+/// assert!((s.eval(mu) - mu.li_exp(3)).norm() < 4.0*std::f64::EPSILON);
+/// ```
+pub fn li_exp_unity(n: i32, order: u32) -> LogSeries {
+    let len = order as usize + 1;
+    let mut regular: Vec<f64> = (0..len).map(|k| zeta_inv_fac(n, k as i32)).collect();
+    let mut log = vec![0.0; len];
+    let mut pole = vec![];
+
+    if n >= 1 {
+        let k = (n - 1) as usize;
+        if k < len {
+            let h = if n == 1 { 0.0 } else { harmonic(n - 1) };
+            regular[k] = h*inv_fac(n - 1);
+            log[k] = -inv_fac(n - 1);
+        }
+    } else {
+        pole = vec![0.0; (1 - n) as usize];
+        pole[(-n) as usize] = if n & 1 == 0 { -fac(-n) } else { fac(-n) };
+    }
+
+    LogSeries { pole, regular, log }
+}
+
+/// Returns the coefficients [a_0, ..., a_n] of the asymptotic
+/// expansion of Li(n,z) for large |z| in powers of ln(-z),
+///
+/// Li(n,z) = sum(k=0:n, a_k ln(-z)^k) + O(1/z)
+///
+/// for integers n >= 0, which follow from the inversion formula
+///
+/// Li(n,z) + (-1)^n Li(n,1/z) = -ln(-z)^n/n! + 2 sum(k=1:(n/2), Li(2k,-1) ln(-z)^(n-2k)/(n-2k)!)
+///
+/// for n >= 1 and Li(0,z) + Li(0,1/z) = -1.  For n < 0 the
+/// polynomial vanishes and an empty vector is returned.
+///
+/// # Example:
+/// ```
+/// use polylog::expansions::li_asymptotic_coeffs;
+///
+/// // Li(2,z) = -ln(-z)^2/2 - pi^2/6 + O(1/z)
+/// let a = li_asymptotic_coeffs(2);
+/// let z2 = std::f64::consts::PI.powi(2)/6.0;
+/// assert!((a[0] + z2).abs() < 2.0*std::f64::EPSILON);
+/// assert!(a[1] == 0.0 && a[2] == -0.5);
+/// ```
+pub fn li_asymptotic_coeffs(n: i32) -> Vec<f64> {
+    if n < 0 {
+        return vec![];
+    } else if n == 0 {
+        return vec![-1.0];
+    }
+
+    let mut a = vec![0.0; n as usize + 1];
+    a[n as usize] = -inv_fac(n);
+
+    for k in 1..=(n/2) {
+        a[(n - 2*k) as usize] = 2.0*neg_eta(2*k)*inv_fac(n - 2*k);
+    }
+
+    a
+}
+
+/// Returns the asymptotic expansion of Li(n,z) for large |z|,
+///
+/// Li(n,z) = sum(k=0:n, a_k ln(-z)^k) - (-1)^n sum(j=1:order, z^(-j)/j^n) + R
+///
+/// for all integers n and complex z, together with an estimate of the
+/// absolute value of the remainder R, where a_k are the coefficients
+/// returned by `li_asymptotic_coeffs()`.  For |z| > 1 the remainder
+/// is bounded by the geometric series of the first omitted term, for
+/// |z| <= 1 the estimate is infinite.  The logarithm ln(-z) is taken
+/// on the principal branch, which reproduces the value of `Li` for
+/// real z > 1.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::Li;
+/// use polylog::expansions::li_asymptotic;
+///
+/// let z = Complex::new(100.0, 50.0);
+/// let (li, err) = li_asymptotic(3, z, 2);
+/// assert!(err < 1e-6);
+/// assert!((li - z.li(3)).norm() <= err);
+/// ```
+pub fn li_asymptotic(n: i32, z: Complex<f64>, order: u32) -> (Complex<f64>, f64) {
+    if z.is_nan() {
+        return (Complex::new(f64::NAN, f64::NAN), f64::NAN);
+    }
+
+    let l = (-z).cln();
+    let poly = li_asymptotic_coeffs(n).iter().rev().fold(Complex::new(0.0, 0.0), |s, ak| s*l + ak);
+
+    let zi = z.inv();
+    let mut zj = Complex::new(1.0, 0.0); // collects z^(-j)
+    let mut sum = Complex::new(0.0, 0.0);
+
+    for j in 1..=(order as i32) {
+        zj *= zi;
+        sum += zj*(j as f64).powi(-n);
+    }
+
+    let sign = if n & 1 == 0 { 1.0 } else { -1.0 };
+
+    // the ratio of subsequent terms of the remainder decreases with j
+    let r = zi.norm();
+    let m = (order + 1) as f64;
+    let q = r*((m + 1.0)/m).powi((-n).max(0));
+    let err = if q < 1.0 { r.powi(order as i32 + 1)*m.powi(-n)/(1.0 - q) } else { f64::INFINITY };
+
+    (poly - sign*sum, err)
+}
+
+/// returns zeta(n-k)/k! for k >= 0, where the reflection formula
+///
+/// zeta(1-t) = 2 (-1)^(t/2) (t-1)!/(2 pi)^t zeta(t)
+///
+/// for even t is used if zeta(n-k) or k! overflow
+fn zeta_inv_fac(n: i32, k: i32) -> f64 {
+    let s = n - k;
+    let v = zeta(s)*inv_fac(k);
+
+    if s >= 0 || s & 1 == 0 || (k <= 170 && v.is_finite()) {
+        v
+    } else {
+        // t = k - n + 1 and (t-1)!/k! = (k-n)!/k!
+        let t = 1 - s;
+        let ratio = if n >= 0 {
+            ((k - n + 1)..=k).fold(1.0, |p, j| p/(j as f64))
+        } else {
+            ((k + 1)..=(k - n)).fold(1.0, |p, j| p*(j as f64))
+        };
+        let sign = if (t/2) & 1 == 0 { 1.0 } else { -1.0 };
+        sign*2.0*zeta(t)*ratio*(2.0*std::f64::consts::PI).powi(-t)
+    }
+}
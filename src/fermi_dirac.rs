@@ -0,0 +1,428 @@
+//! Complete and incomplete Fermi-Dirac integrals
+//!
+//! F_j(eta) = 1/Gamma(j+1) int(t=0:Inf, t^j/(e^(t-eta) + 1)) = -Li(j+1,-e^eta)
+//!
+//! for integer and half-integer j, and
+//!
+//! F_j(eta,b) = 1/Gamma(j+1) int(t=b:Inf, t^j/(e^(t-eta) + 1))
+//!
+//! for integer j >= 0 and b >= 0.
+//!
+//! # Example:
+//! ```
+//! use polylog::fermi_dirac::{fermi_dirac, incomplete_fermi_dirac};
+//!
+//! assert!((fermi_dirac(0.0, 1.0) - 1.3132616875182228_f64).abs() < 2.0*std::f64::EPSILON);
+//! assert!((fermi_dirac(0.5, 1.0) - 1.5756407761513003_f64).abs() < 4.0*std::f64::EPSILON);
+//! assert!(fermi_dirac(1.0, 1000.0).is_finite());
+//! assert!((incomplete_fermi_dirac(1, 1.0, 2.0) - 0.96517137143989785_f64).abs() < 4.0*std::f64::EPSILON);
+//! ```
+
+use crate::Li;
+use crate::li::eta::neg_eta;
+use crate::li::fac::inv_fac;
+use crate::quad::{gauss_legendre, integrate, integrate_tail};
+
+/// Returns the complete Fermi-Dirac integral
+///
+/// F_j(eta) = -Li(j+1,-e^eta)
+///
+/// for integer and half-integer j and real eta.  For j > -1 this is
+/// equal to F_j(eta) = 1/Gamma(j+1) int(t=0:Inf, t^j/(e^(t-eta) + 1)).
+///
+/// For integer j, F_j(eta) is calculated from the real polylogarithm
+/// `Li` with the argument -e^(-eta) for eta > 0, such that e^eta is
+/// never formed.  For half-integer j, F_j(eta) is calculated from
+/// the series in e^eta for eta < -1, from the asymptotic expansion
+///
+/// F_j(eta) = 2 sum(k=0:Inf, eta(2k) eta^(j+1-2k)/Gamma(j+2-2k))
+///
+/// for large eta, where eta(2k) is the Dirichlet eta function, and by
+/// numerical integration otherwise.
+///
+/// # Panics
+///
+/// Panics if j is neither an integer nor a half-integer.
+pub fn fermi_dirac(j: f64, eta: f64) -> f64 {
+    if !(2.0*j).is_finite() || (2.0*j).fract() != 0.0 {
+        panic!("fermi_dirac not implemented for non-integer and non-half-integer j (given value: j = {})", j);
+    }
+
+    if eta.is_nan() {
+        f64::NAN
+    } else if j.fract() == 0.0 {
+        fd_int(j as i32, eta)
+    } else {
+        fd_half(j, eta)
+    }
+}
+
+/// Returns the incomplete Fermi-Dirac integral
+///
+/// F_j(eta,b) = 1/j! int(t=b:Inf, t^j/(e^(t-eta) + 1))
+///
+/// for integer j >= 0, real eta and real b >= 0.  For b = 0 this is
+/// equal to the complete Fermi-Dirac integral F_j(eta).  Returns NaN
+/// for b < 0.
+///
+/// Substituting t = b + u and expanding (b + u)^j yields the finite
+/// sum
+///
+/// F_j(eta,b) = sum(k=0:j, b^(j-k)/(j-k)! F_k(eta-b))
+///            = -sum(k=0:j, b^(j-k)/(j-k)! Li(k+1,-e^(eta-b)))
+///
+/// All terms are positive in both regimes b > eta and b < eta, and
+/// each F_k(eta-b) is evaluated without forming e^(eta-b) for b < eta,
+/// such that no cancellations occur.  For b < 0 the terms alternate
+/// in sign and cancel, which is why b < 0 is not supported.
+///
+/// # Panics
+///
+/// Panics if j < 0.
+pub fn incomplete_fermi_dirac(j: i32, eta: f64, b: f64) -> f64 {
+    if j < 0 {
+        panic!("incomplete_fermi_dirac not implemented for j < 0 (given value: j = {})", j);
+    }
+
+    if eta.is_nan() || b.is_nan() || b < 0.0 {
+        f64::NAN
+    } else if b == 0.0 {
+        fd_int(j, eta)
+    } else if b == f64::INFINITY {
+        0.0
+    } else {
+        let x = eta - b;
+        let mut p = 1.0; // collects b^(j-k)/(j-k)!
+        let mut sum = 0.0;
+
+        for k in (0..=j).rev() {
+            let f = fd_int(k, x);
+            if f != 0.0 {
+                sum += p*f;
+            }
+            p *= b/((j - k + 1) as f64);
+        }
+
+        if !sum.is_normal() && x < -40.0 {
+            ifd_log(j, x, b)
+        } else {
+            sum
+        }
+    }
+}
+
+/// returns F_j(eta,b) for b > 0 and x = eta - b < -40, where
+/// b^(j-k)/(j-k)! or F_k(x) is not representable as f64.  For x < -40
+/// one has F_k(x) = e^x to double precision, such that
+///
+/// F_j(eta,b) = sum(m=0:j, e^x b^m/m!)
+///
+/// where e^x is split into two factors e^(x/2) to avoid an underflow.
+/// If b^m/m! overflows, the term is calculated from logarithms.
+fn ifd_log(j: i32, x: f64, b: f64) -> f64 {
+    let h = (0.5*x).exp();
+    let lb = b.ln();
+    let mut p = 1.0; // collects b^m/m!
+    let mut lfac = 0.0; // collects ln(m!)
+    let mut sum = 0.0;
+
+    for m in 0..=j {
+        if m > 0 {
+            p *= b/(m as f64);
+            lfac += (m as f64).ln();
+        }
+        sum += if p.is_finite() {
+            (p*h)*h
+        } else {
+            (x + (m as f64)*lb - lfac).exp()
+        };
+    }
+
+    sum
+}
+
+/// returns true if x is even, false otherwise
+fn is_even(x: i32) -> bool {
+    x & 1 == 0
+}
+
+/// returns F_j(eta) for integer j
+fn fd_int(j: i32, eta: f64) -> f64 {
+    let s = j + 1;
+
+    if eta <= 0.0 {
+        -(-eta.exp()).li(s)
+    } else {
+        // inversion formula in terms of x = -e^(-eta)
+        let x = -(-eta).exp();
+        let sgn = if is_even(s) { 1.0 } else { -1.0 };
+        if s > 0 {
+            fd_int_rest(s, eta) + sgn*x.li(s)
+        } else if s == 0 {
+            1.0/(1.0 - x)
+        } else {
+            sgn*x.li(s)
+        }
+    }
+}
+
+/// returns r.h.s. of inversion formula for s > 0 and eta > 0:
+///
+/// -Li(s,-e^eta) - (-1)^s Li(s,-e^(-eta))
+///    = eta^s/s! - 2 sum(k=1:(s÷2), eta^(s-2k)/(s-2k)! Li(2k,-1))
+fn fd_int_rest(s: i32, eta: f64) -> f64 {
+    let eta2 = eta*eta;
+    let mut p = if is_even(s) { 1.0 } else { eta }; // collects eta^(s-2k)
+    let mut sum = 0.0;
+
+    for k in (1..=(s/2)).rev() {
+        sum -= 2.0*neg_eta(2*k)*inv_fac(s - 2*k)*p;
+        p *= eta2;
+    }
+
+    sum + p*inv_fac(s)
+}
+
+/// returns F_j(eta) for half-integer j
+fn fd_half(j: f64, eta: f64) -> f64 {
+    if eta == f64::INFINITY {
+        if j > -1.0 { f64::INFINITY } else { 0.0 }
+    } else if eta < -1.0 + 2.0*j.min(0.0) {
+        fd_series(j, eta)
+    } else if eta > 40.0 - 4.0*j.min(0.0) {
+        fd_asymptotic(j, eta)
+    } else {
+        fd_quad(j, eta)
+    }
+}
+
+/// returns F_j(eta) from the series for eta < 0:
+///
+/// F_j(eta) = sum(k=1:Inf, (-1)^(k+1) e^(k eta)/k^(j+1))
+fn fd_series(j: f64, eta: f64) -> f64 {
+    let s = j + 1.0;
+    let x = eta.exp();
+    let mut sum = 0.0;
+    let mut xk = -1.0; // collects (-1)^(k+1) x^k
+
+    for k in 1..i32::MAX {
+        xk *= -x;
+        let old_sum = sum;
+        sum += xk/(k as f64).powf(s);
+        if sum == old_sum { break; }
+    }
+
+    sum
+}
+
+/// returns F_j(eta) from the asymptotic expansion for large eta
+/// and half-integer j:
+///
+/// F_j(eta) = 2 sum(k=0:Inf, eta(2k) eta^(j+1-2k)/Gamma(j+2-2k))
+///
+/// The series is truncated at its smallest term.
+fn fd_asymptotic(j: f64, eta: f64) -> f64 {
+    let s = j + 1.0;
+    let ieta2 = 1.0/(eta*eta);
+    let mut p = eta.powf(s)/gamma_half(s + 1.0); // collects eta^(s-2k)/Gamma(s+1-2k)
+    if !p.is_normal() {
+        p = (s*eta.ln() - ln_gamma_half(s + 1.0)).exp()*gamma_half_sign(s + 1.0);
+    }
+    let mut sum = p; // k = 0 term: 2 eta(0) = 1
+    let mut old_term = f64::INFINITY;
+
+    for k in 1..i32::MAX {
+        let a = s - 2.0*(k as f64);
+        p *= (a + 2.0)*(a + 1.0)*ieta2;
+        let term = -2.0*neg_eta(2*k)*p;
+        if term.abs() >= old_term { break; }
+        let old_sum = sum;
+        sum += term;
+        if sum == old_sum { break; }
+        old_term = term.abs();
+    }
+
+    sum
+}
+
+/// returns F_j(eta) for half-integer j by numerical integration
+///
+/// For j >= -1/2:
+///
+/// F_j(eta) = 1/Gamma(j+1) int(t=0:Inf, t^j f(t-eta))
+///
+/// with the Fermi function f(x) = 1/(e^x + 1).  For j < -1/2,
+/// F_j(eta) = d^m/deta^m F_(j+m)(eta) with j + m = -1/2, see
+/// fd_quad_neg().
+fn fd_quad(j: f64, eta: f64) -> f64 {
+    if j < -0.5 {
+        return fd_quad_neg((-0.5 - j) as usize, eta);
+    }
+
+    // integrate over t in [0,4] with t = u^2
+    let fu = |u: f64| 2.0*u.powf(2.0*j + 1.0)*fermi(u*u - eta);
+    let sum = gauss_legendre(fu, 0.0, 1.0) + gauss_legendre(fu, 1.0, 2.0)
+        + integrate_tail(|t| t.powf(j)*fermi(t - eta), 4.0, eta.max(j));
+
+    sum/gamma_half(j + 1.0)
+}
+
+/// returns F_j(eta) for j = -1/2 - m with m > 0 by numerical
+/// integration of
+///
+/// F_j(eta) = 1/Gamma(1/2) int(t=0:Inf, t^(-1/2) (-1)^m f^(m)(t-eta))
+///
+/// For eta > 2 the integral is split at t = c, and the integral over
+/// [c,Inf) is integrated by parts m times to avoid the cancellations
+/// of the oscillating integrand f^(m)(t-eta):
+///
+/// int(t=c:Inf, g(t) (-1)^m f^(m)(t-eta))
+///    = -sum(k=0:(m-1), (-1)^(m+k) g^(k)(c) f^(m-1-k)(c-eta))
+///      + int(t=c:Inf, g^(m)(t) f(t-eta))
+///
+/// where g(t) = t^(-1/2).
+fn fd_quad_neg(m: usize, eta: f64) -> f64 {
+    let sgn = |k: usize| if k & 1 == 0 { 1.0 } else { -1.0 };
+    let coeffs = fermi_deriv_coeffs(m);
+    let fm = |x: f64| sgn(m)*fermi_deriv(&coeffs, x);
+    let c = if eta > 2.0 { (0.5*eta).max(eta - 15.0) } else { f64::INFINITY };
+
+    // integrate over t in [0,min(4,c)] with t = u^2
+    let fu = |u: f64| 2.0*fm(u*u - eta);
+    let mut sum = if c < 4.0 {
+        gauss_legendre(fu, 0.0, c.sqrt())
+    } else {
+        gauss_legendre(fu, 0.0, 1.0) + gauss_legendre(fu, 1.0, 2.0)
+    };
+
+    if c == f64::INFINITY {
+        sum += integrate_tail(|t| t.powf(-0.5)*fm(t - eta), 4.0, eta);
+    } else {
+        if c > 4.0 {
+            sum += integrate(|t| t.powf(-0.5)*fm(t - eta), 4.0, c);
+        }
+
+        // boundary terms, gk = g^(k)(c)
+        let mut gk = c.powf(-0.5);
+        for k in 0..m {
+            let fk = fermi_deriv(&fermi_deriv_coeffs(m - 1 - k), c - eta);
+            sum -= sgn(m + k)*gk*fk;
+            gk *= (-0.5 - k as f64)/c;
+        }
+
+        // gm = g^(m)(t)/t^(-1/2-m)
+        let gm = gk*c.powf(0.5 + m as f64);
+        sum += gm*integrate_tail(|t| t.powf(-0.5 - m as f64)*fermi(t - eta), c, eta);
+    }
+
+    sum/std::f64::consts::PI.sqrt()
+}
+
+/// returns the Fermi function f(x) = 1/(e^x + 1)
+pub(crate) fn fermi(x: f64) -> f64 {
+    if x > 0.0 {
+        let e = (-x).exp();
+        e/(1.0 + e)
+    } else {
+        1.0/(1.0 + x.exp())
+    }
+}
+
+/// returns the coefficients c_k of the m-th derivative of the Fermi
+/// function f(x) = 1/(e^x + 1), f^(m)(x) = sum(k=1:(m+1), c_k f(x)^k),
+/// where c_0 = 0
+fn fermi_deriv_coeffs(m: usize) -> Vec<f64> {
+    let mut c = vec![0.0, 1.0];
+
+    // f' = f^2 - f  =>  (f^k)' = k f^(k+1) - k f^k
+    for _ in 0..m {
+        let mut d = vec![0.0; c.len() + 1];
+        for (k, &ck) in c.iter().enumerate() {
+            d[k + 1] += (k as f64)*ck;
+            d[k] -= (k as f64)*ck;
+        }
+        c = d;
+    }
+
+    c
+}
+
+/// returns f^(m)(x) for the Fermi function f(x) = 1/(e^x + 1), where
+/// coeffs are the coefficients returned by fermi_deriv_coeffs(m)
+fn fermi_deriv(coeffs: &[f64], x: f64) -> f64 {
+    // for x < 0 use f(x) = 1 - f(-x) to avoid cancellations
+    let m = coeffs.len() - 2;
+    let (f, sgn) = if x < 0.0 && m > 0 {
+        (fermi(-x), if m & 1 == 0 { -1.0 } else { 1.0 })
+    } else {
+        (fermi(x), 1.0)
+    };
+
+    sgn*coeffs.iter().rev().fold(0.0, |acc, c| acc*f + c)
+}
+
+/// returns Gamma(x) for integer x > 0 and half-integer x
+pub(crate) fn gamma_half(x: f64) -> f64 {
+    if x < 0.0 {
+        // reflection formula, |sin(pi x)| = 1 for half-integer x
+        return gamma_half_sign(x)*std::f64::consts::PI/gamma_half(1.0 - x);
+    }
+
+    let (mut y, mut prod) = if x.fract() == 0.0 {
+        (1.0, 1.0)
+    } else {
+        (0.5, std::f64::consts::PI.sqrt())
+    };
+
+    while y < x {
+        prod *= y;
+        y += 1.0;
+    }
+
+    prod
+}
+
+/// returns ln|Gamma(x)| for integer x > 0 and half-integer x
+fn ln_gamma_half(x: f64) -> f64 {
+    if x < 0.0 {
+        // reflection formula, |sin(pi x)| = 1 for half-integer x
+        return std::f64::consts::PI.ln() - ln_gamma_half(1.0 - x);
+    }
+
+    let mut y = if x.fract() == 0.0 { 1.0 } else { 0.5 };
+    let mut sum = if y == 1.0 { 0.0 } else { 0.5*std::f64::consts::PI.ln() };
+
+    while y < x {
+        sum += y.ln();
+        y += 1.0;
+    }
+
+    sum
+}
+
+/// returns the sign of Gamma(x) for half-integer x
+fn gamma_half_sign(x: f64) -> f64 {
+    if x > 0.0 || is_even((0.5 - x) as i32) { 1.0 } else { -1.0 }
+}
+
+#[test]
+fn test_fermi_deriv_coeffs() {
+    assert!(fermi_deriv_coeffs(0) == vec![0.0, 1.0]);
+    assert!(fermi_deriv_coeffs(1) == vec![0.0, -1.0, 1.0]);
+    assert!(fermi_deriv_coeffs(2) == vec![0.0, 1.0, -3.0, 2.0]);
+}
+
+#[test]
+fn test_ln_gamma_half() {
+    let eps = 1e-14;
+    assert!(ln_gamma_half(1.0) == 0.0);
+    assert!((ln_gamma_half(0.5) - 0.5*std::f64::consts::PI.ln()).abs() < eps);
+    assert!((ln_gamma_half(5.0) - 24.0_f64.ln()).abs() < eps);
+    assert!((ln_gamma_half(3.5) - (15.0/8.0*std::f64::consts::PI.sqrt()).ln()).abs() < eps);
+    assert!((ln_gamma_half(-0.5) - (2.0*std::f64::consts::PI.sqrt()).ln()).abs() < eps);
+    assert!((gamma_half(-0.5) + 2.0*std::f64::consts::PI.sqrt()).abs() < eps);
+    assert!(gamma_half(5.0) == 24.0);
+    assert!(gamma_half_sign(-0.5) == -1.0);
+    assert!(gamma_half_sign(-1.5) == 1.0);
+    assert!(gamma_half_sign(0.5) == 1.0);
+}
@@ -0,0 +1,266 @@
+//! Integrals of polylogarithms
+//!
+//! I(n,k,z) = int(t=0:z, t^k Li(n,t))
+//!
+//! for integers n and k, and definite integrals over intervals [a,b].
+//!
+//! # Example:
+//! ```
+//! use polylog::integrals::integral;
+//!
+//! // int(t=0:1, Li(2,t)) = zeta(2) - 1
+//! assert!((integral(2, 0, 0.0, 1.0) - 0.6449340668482264_f64).abs() < 4.0*std::f64::EPSILON);
+//! ```
+
+use num::complex::Complex;
+use crate::cln::CLn;
+use crate::Li;
+use crate::li::zeta::zeta;
+
+/// Returns the antiderivative
+///
+/// I(n,k,z) = int(t=0:z, t^k Li(n,t))
+///
+/// of t^k Li(n,t) for integers n and k and complex z.  For k = -1 the
+/// antiderivative is I(n,-1,z) = Li(n+1,z).  For k != -1 it is
+/// calculated by repeated integration by parts,
+///
+/// I(n,k,z) = z^(k+1)/(k+1) Li(n,z) - 1/(k+1) I(n-1,k,z),
+///
+/// down to n = 0 for n > 0 and up to n = 0 for n < 0, where
+///
+/// I(0,k,z) = int(t^(k+1)/(1-t)) = -ln(1-z) - sum(j=1:k+1, z^j/j)
+///
+/// for k >= 0, where for |z| < 1 and |z|^(k+1) < 1/2 the remainder
+/// sum(j=k+2:Inf, z^j/j) is summed directly to avoid cancellations, and
+///
+/// I(0,k,z) = ln(z) - ln(1-z) - sum(j=2:-k-1, z^(1-j)/(j-1))
+///
+/// for k <= -2.  For k <= -2 the integral over t diverges at t = 0 and
+/// I(n,k,z) is the antiderivative with the integration constant given
+/// by the above formula.  The branch cuts of I(n,k,z) are the
+/// interval [1,Inf) of Li(n,z) and, for k <= -2, the interval (-Inf,0]
+/// of ln(z), where the values are taken as in `Li` and `CLn`,
+/// respectively.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::integrals::antiderivative;
+///
+/// let z = Complex::new(0.5, 0.5);
+/// let i = antiderivative(2, 1, z);
+/// assert!((i - Complex::new(-0.10112366522764298, 0.079024159351939391)).norm() < 4.0*std::f64::EPSILON);
+/// ```
+pub fn antiderivative(n: i32, k: i32, z: Complex<f64>) -> Complex<f64> {
+    if z.is_nan() {
+        Complex::new(f64::NAN, f64::NAN)
+    } else if k == -1 {
+        z.li(n + 1)
+    } else if n >= 1 && z.re == 1.0 && z.im == 0.0 {
+        Complex::new(antiderivative_one(n, k), 0.0)
+    } else {
+        let a = (k + 1) as f64;
+        let za = z.powi(k + 1);
+
+        if n >= 0 {
+            let mut sum = Complex::new(0.0, 0.0);
+            let mut p = 1.0/a; // collects (-1)^j/a^(j+1)
+            for j in 0..n {
+                sum += p*za*z.li(n - j);
+                p /= -a;
+            }
+            sum + p*a*base(k, z)
+        } else {
+            let mut sum = Complex::new(0.0, 0.0);
+            let mut p = 1.0; // collects (-a)^j
+            for j in 0..(-n) {
+                sum += p*za*z.li(n + 1 + j);
+                p *= -a;
+            }
+            sum + p*base(k, z)
+        }
+    }
+}
+
+/// Returns the definite integral
+///
+/// int(t=a:b, t^k Re[Li(n,t)])
+///
+/// for integers n and k and real a and b.  The integral is calculated
+/// from the antiderivative along the real axis below the branch cut
+/// [1,Inf) of Li(n,t), where the real part of Li(n,t) is continuous.
+///
+/// Returns NaN if the integral diverges, i.e. if the interval contains
+/// t = 0 for k <= -2 or t = 1 for n <= 0.
+///
+/// # Example:
+/// ```
+/// use polylog::integrals::integral;
+///
+/// // int(t=0:2, t Re[Li(1,t)]) = 2
+/// assert!((integral(1, 1, 0.0, 2.0) - 2.0).abs() < 4.0*std::f64::EPSILON);
+/// ```
+pub fn integral(n: i32, k: i32, a: f64, b: f64) -> f64 {
+    let (lo, hi) = (a.min(b), a.max(b));
+
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else if a == b {
+        0.0
+    } else if (k <= -2 && lo <= 0.0 && hi >= 0.0) || (n <= 0 && lo <= 1.0 && hi >= 1.0) {
+        f64::NAN
+    } else {
+        let f = |x: f64| antiderivative(n, k, Complex::new(x, 0.0));
+        (f(b) - f(a)).re
+    }
+}
+
+/// Returns the definite integral
+///
+/// int(t=a:b, t^k Li(n,t))
+///
+/// for integers n and k along the straight line from the complex
+/// number a to the complex number b.
+///
+/// If the path crosses a branch cut of the antiderivative, it is split
+/// at the crossing point c, where the antiderivative is evaluated on
+/// both sides of the cut, using the reflection I(n,k,conj(c)) =
+/// conj(I(n,k,c)).  End points on a branch cut take the value on the
+/// side of the path.  A path along the real axis takes the values
+/// of `Li` on the branch cut [1,Inf).
+///
+/// Returns NaN if the integral diverges, i.e. if the path contains
+/// t = 0 for k <= -2 or t = 1 for n <= 0.
+///
+/// # Example:
+/// ```
+/// use num::complex::Complex;
+/// use polylog::integrals::integral_complex;
+///
+/// // closed path around the branch point z = 1
+/// let a = Complex::new(2.0, -1.0);
+/// let b = Complex::new(2.0, 1.0);
+/// let c = Complex::new(0.0, 0.0);
+/// let i = integral_complex(1, 0, a, b) + integral_complex(1, 0, b, c) + integral_complex(1, 0, c, a);
+/// assert!((i - Complex::new(0.0, -2.0*std::f64::consts::PI)).norm() < 1e-14);
+/// ```
+pub fn integral_complex(n: i32, k: i32, a: Complex<f64>, b: Complex<f64>) -> Complex<f64> {
+    if a.is_nan() || b.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    } else if a == b {
+        return Complex::new(0.0, 0.0);
+    }
+
+    // singular points on the path
+    let singular = |z: Complex<f64>| {
+        (k <= -2 && z.re == 0.0 && z.im == 0.0) || (n <= 0 && z.re == 1.0 && z.im == 0.0)
+    };
+    let on_path = |z: Complex<f64>| {
+        let d = b - a;
+        let t = ((z - a)*d.conj()).re/d.norm_sqr();
+        (0.0..=1.0).contains(&t) && (a + t*d - z).norm() <= f64::EPSILON*(1.0 + z.norm())
+    };
+
+    if (k <= -2 && on_path(Complex::new(0.0, 0.0))) || (n <= 0 && on_path(Complex::new(1.0, 0.0))) || singular(a) || singular(b) {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let fa = end_value(n, k, a, b);
+    let fb = end_value(n, k, b, a);
+
+    if a.im*b.im < 0.0 {
+        // crossing point with the real axis
+        let t = a.im/(a.im - b.im);
+        let c = a.re + t*(b.re - a.re);
+        if is_on_cut(k, c) {
+            return (side_value(n, k, c, a.im > 0.0) - fa) + (fb - side_value(n, k, c, b.im > 0.0));
+        }
+    }
+
+    fb - fa
+}
+
+/// returns true if the real x is on a branch cut of I(n,k,x)
+fn is_on_cut(k: i32, x: f64) -> bool {
+    x > 1.0 || (k <= -2 && x < 0.0)
+}
+
+/// returns I(n,k,x) for real x on a branch cut, approached from above
+/// or below the real axis
+fn side_value(n: i32, k: i32, x: f64, above: bool) -> Complex<f64> {
+    // Li(n,x) is evaluated below the cut [1,Inf) and ln(x) above the
+    // cut (-Inf,0]
+    let v = antiderivative(n, k, Complex::new(x, 0.0));
+    if above == (x < 0.0) { v } else { v.conj() }
+}
+
+/// returns I(n,k,z) at the end point z of a path, whose other end
+/// point is w
+fn end_value(n: i32, k: i32, z: Complex<f64>, w: Complex<f64>) -> Complex<f64> {
+    if z.im == 0.0 && w.im != 0.0 && is_on_cut(k, z.re) {
+        side_value(n, k, z.re, w.im > 0.0)
+    } else {
+        antiderivative(n, k, z)
+    }
+}
+
+/// returns I(n,k,1) for n >= 1 and k != -1
+fn antiderivative_one(n: i32, k: i32) -> f64 {
+    let a = (k + 1) as f64;
+    let mut sum = 0.0;
+    let mut p = 1.0/a; // collects (-1)^j/a^(j+1)
+
+    for j in 0..(n - 1) {
+        sum += p*zeta(n - j);
+        p /= -a;
+    }
+
+    // (z^(k+1) - 1) ln(1-z) -> 0 for z -> 1
+    let h = if k >= 0 {
+        (1..=(k + 1)).map(|j| 1.0/(j as f64)).sum::<f64>()
+    } else {
+        (1..(-k - 1)).map(|j| 1.0/(j as f64)).sum::<f64>()
+    };
+
+    sum + p*h
+}
+
+/// returns I(0,k,z) = int(t^(k+1)/(1-t)) for k != -1
+fn base(k: i32, z: Complex<f64>) -> Complex<f64> {
+    if k >= 0 {
+        let q = k + 1;
+        let r = z.norm();
+        if r < 1.0 && r.powi(q) < 0.5 {
+            // sum(j=q+1:Inf, z^j/j), which is small compared to the
+            // subtracted terms z^j/j for j <= q
+            let mut zj = z.powi(q); // collects z^j
+            let mut sum = Complex::new(0.0, 0.0);
+            for j in (q + 1)..i32::MAX {
+                zj *= z;
+                let old_sum = sum;
+                sum += zj/(j as f64);
+                if sum == old_sum { break; }
+            }
+            sum
+        } else {
+            let mut zj = Complex::new(1.0, 0.0); // collects z^j
+            let mut sum = z.li(1);
+            for j in 1..=q {
+                zj *= z;
+                sum -= zj/(j as f64);
+            }
+            sum
+        }
+    } else {
+        let p = -k - 1;
+        let zi = z.inv();
+        let mut zj = Complex::new(1.0, 0.0); // collects z^(1-j)
+        let mut sum = z.cln() + z.li(1);
+        for j in 2..=p {
+            zj *= zi;
+            sum -= zj/((j - 1) as f64);
+        }
+        sum
+    }
+}
@@ -0,0 +1,283 @@
+use num::complex::Complex;
+use crate::cln::expm1;
+use crate::{Li, LiDeriv};
+use crate::li::eta::neg_eta;
+use crate::li::fac::fac;
+use crate::li::zeta::zeta;
+
+/// Provides the inverse `li_inv()` of the n-th order polylogarithm
+/// for a value y of type `T`, i.e. `y.li_inv(n)` returns x with
+/// Li(n,x) = y.
+///
+/// For real y the inverse is taken on the branch x <= 1, where Li(n,x)
+/// is monotonically increasing for n >= 0.  For n >= 2 the function
+/// Li(n,x) is bounded by zeta(n) on this branch and NaN is returned for
+/// y > zeta(n).  For n < 0 the function Li(n,x) is not monotonic on
+/// x <= 1 and NaN is returned.
+///
+/// For complex y the equation Li(n,z) = y is solved by Newton's method,
+/// using the derivative
+///
+/// d/dz Li(n,z) = Li(n-1,z)/z.
+///
+/// Since the inverse is not unique, `li_inv_from()` allows to select
+/// the solution by the start value z0 of the iteration.  NaN is
+/// returned if the iteration does not converge.  For n = 1 the
+/// inverse z = 1 - e^(-y) is unique and NaN is returned for
+/// |Im(y)| >= pi, which is not in the range of Li(1,z).
+pub trait LiInv<T> {
+    fn li2_inv(&self) -> T;
+    fn li3_inv(&self) -> T;
+    fn li_inv(&self, n: i32) -> T;
+    fn li_inv_from(&self, n: i32, z0: T) -> T;
+}
+
+impl LiInv<f64> for f64 {
+    /// Returns the real x <= 1 with Li2(x) = y for a real number y of
+    /// type `f64`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Li2, LiInv};
+    ///
+    /// assert!((0.5_f64.li2().li2_inv() - 0.5_f64).abs() < 2.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li2_inv(&self) -> f64 {
+        self.li_inv(2)
+    }
+
+    /// Returns the real x <= 1 with Li3(x) = y for a real number y of
+    /// type `f64`.
+    fn li3_inv(&self) -> f64 {
+        self.li_inv(3)
+    }
+
+    /// Returns the real x <= 1 with Li(n,x) = y for a real number y of
+    /// type `f64` and `n >= 0`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Li, LiInv};
+    ///
+    /// assert!(((-2.0_f64).li(5).li_inv(5) + 2.0_f64).abs() < 4.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_inv(&self, n: i32) -> f64 {
+        rli_inv(n, *self, None)
+    }
+
+    /// Returns the real x <= 1 with Li(n,x) = y for a real number y of
+    /// type `f64` and `n >= 0`, where x0 is used as start value of the
+    /// iteration.  Since the solution on x <= 1 is unique, the result
+    /// does not depend on x0.
+    fn li_inv_from(&self, n: i32, x0: f64) -> f64 {
+        rli_inv(n, *self, Some(x0))
+    }
+}
+
+impl LiInv<Complex<f64>> for Complex<f64> {
+    /// Returns a complex z with Li2(z) = w for a complex number w of
+    /// type `Complex<f64>`.
+    fn li2_inv(&self) -> Complex<f64> {
+        self.li_inv(2)
+    }
+
+    /// Returns a complex z with Li3(z) = w for a complex number w of
+    /// type `Complex<f64>`.
+    fn li3_inv(&self) -> Complex<f64> {
+        self.li_inv(3)
+    }
+
+    /// Returns a complex z with Li(n,z) = w for a complex number w of
+    /// type `Complex<f64>` for all integers `n`.
+    ///
+    /// For real w <= zeta(n) and n >= 2 the real solution z <= 1 is
+    /// returned.  Otherwise the iteration starts at z0 = w for |w| <= 1.
+    /// For |w| > 1 it starts at the solution of the asymptotic form
+    /// Li(n,z) ~ -ln(-z)^n/n! for n >= 1 and at the solution of the
+    /// pole term Li(n,z) ~ Gamma(1-n) (-ln(z))^(n-1) close to z = 1 for
+    /// n <= 0.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiInv};
+    ///
+    /// let w = Complex::new(1.0_f64, 1.0_f64);
+    /// assert!((w.li_inv(3).li(3) - w).norm() < 4.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_inv(&self, n: i32) -> Complex<f64> {
+        let w = *self;
+
+        if n >= 2 && w.im == 0.0 && w.re <= zeta(n) {
+            Complex::new(rli_inv(n, w.re, None), 0.0)
+        } else if w.norm() <= 1.0 {
+            cli_inv(n, w, w)
+        } else if n >= 1 {
+            // -ln(-z)^n/n! = w
+            let fac = (1..=n).fold(1.0, |p, i| p*(i as f64));
+            let l = (-fac*w).powf(1.0/(n as f64));
+            cli_inv(n, w, -l.exp())
+        } else {
+            // Gamma(1-n) (-ln(z))^(n-1) = w
+            let l = (w/fac(-n)).powf(1.0/((n - 1) as f64));
+            cli_inv(n, w, (-l).exp())
+        }
+    }
+
+    /// Returns the complex z with Li(n,z) = w for a complex number w of
+    /// type `Complex<f64>` for all integers `n`, which is reached by
+    /// Newton's method from the start value z0.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::LiInv;
+    ///
+    /// // Li(-1,z) = z/(1-z)^2 = 2 for z = 1/2 and z = 2
+    /// let w = Complex::new(2.0_f64, 0.0_f64);
+    /// assert!((w.li_inv_from(-1, Complex::new(0.4, 0.1)) - 0.5).norm() < 4.0_f64*std::f64::EPSILON);
+    /// assert!((w.li_inv_from(-1, Complex::new(2.5, 0.1)) - 2.0).norm() < 8.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_inv_from(&self, n: i32, z0: Complex<f64>) -> Complex<f64> {
+        cli_inv(n, *self, z0)
+    }
+}
+
+/// returns the real x <= 1 with Li(n,x) = y, where x0 is used as start
+/// value of the iteration, if given
+fn rli_inv(n: i32, y: f64, x0: Option<f64>) -> f64 {
+    if y.is_nan() || n < 0 {
+        f64::NAN
+    } else if n == 0 {
+        // Li(0,x) = x/(1-x) > -1
+        if y < -1.0 { f64::NAN } else { y/(1.0 + y) }
+    } else if n == 1 {
+        // Li(1,x) = -ln(1-x)
+        -(-y).exp_m1()
+    } else if y > zeta(n) {
+        f64::NAN
+    } else if y == zeta(n) {
+        1.0
+    } else if y == 0.0 || y == f64::NEG_INFINITY {
+        y
+    } else {
+        rli_inv_newton(n, y, x0)
+    }
+}
+
+/// returns the real x < 1 with Li(n,x) = y for n >= 2 and
+/// -Inf < y < zeta(n), y != 0
+fn rli_inv_newton(n: i32, y: f64, x0: Option<f64>) -> f64 {
+    let f = |x: f64| x.li(n) - y;
+
+    // bracket [lo,hi] of the root, using x <= Li(n,x) <= zeta(n) x for
+    // 0 <= x <= 1, x <= Li(n,x) <= eta(n) x for -1 <= x <= 0 and
+    // x <= Li(n,x) for x < -1
+    let (mut lo, mut hi) = if y > 0.0 {
+        (y/zeta(n), y.min(1.0))
+    } else {
+        let eta = -neg_eta(n);
+        let mut lo = (y/eta).max(-1.0);
+        let mut hi = y;
+        while f(lo) > 0.0 {
+            hi = lo;
+            lo *= 2.0;
+            if lo == f64::NEG_INFINITY { return lo; }
+        }
+        (lo, hi)
+    };
+
+    // safeguarded Newton iteration
+    let mut x = match x0 {
+        Some(x0) if x0 > lo && x0 < hi => x0,
+        _ => 0.5*(lo + hi),
+    };
+
+    for _ in 0..200 {
+        let r = f(x);
+        if r == 0.0 { break; }
+        if r < 0.0 { lo = x; } else { hi = x; }
+
+        let mut x_new = x - r/x.li_deriv(n, 1);
+        if !(x_new > lo && x_new < hi) {
+            x_new = 0.5*(lo + hi);
+        }
+
+        let old_x = x;
+        x = x_new;
+        if (x - old_x).abs() <= f64::EPSILON*x.abs() { break; }
+    }
+
+    x
+}
+
+/// returns the complex z with Li(n,z) = w, reached by Newton's method
+/// from the start value z0
+fn cli_inv(n: i32, w: Complex<f64>, z0: Complex<f64>) -> Complex<f64> {
+    if w.is_nan() || z0.is_nan() {
+        Complex::new(f64::NAN, f64::NAN)
+    } else if n == 0 {
+        // Li(0,z) = z/(1-z)
+        w/(1.0 + w)
+    } else if n == 1 {
+        // Li(1,z) = -ln(1-z) with |Im(Li(1,z))| <= pi
+        if w.im.abs() >= std::f64::consts::PI {
+            Complex::new(f64::NAN, f64::NAN)
+        } else {
+            -expm1(-w)
+        }
+    } else if w.re == 0.0 && w.im == 0.0 {
+        w
+    } else {
+        cli_inv_newton(n, w, z0)
+    }
+}
+
+/// returns the complex z with Li(n,z) = w from a damped Newton
+/// iteration, starting at z0; returns NaN if the iteration does not
+/// converge or if the residual cannot be reduced along a Newton step,
+/// which is larger than the rounding errors
+fn cli_inv_newton(n: i32, w: Complex<f64>, z0: Complex<f64>) -> Complex<f64> {
+    let mut z = z0;
+    let mut r = z.li(n) - w;
+
+    for _ in 0..100 {
+        // converged if the residual or the undamped Newton step is
+        // negligible
+        if r.norm() <= 4.0*f64::EPSILON*w.norm() {
+            return z;
+        }
+
+        let newton = r/z.li_deriv(n, 1);
+        if !newton.is_finite() { break; }
+        if newton.norm() <= 2.0*f64::EPSILON*z.norm() {
+            return z - newton;
+        }
+
+        // halve the step until the residual decreases
+        let mut step = newton;
+        let mut z_new = z - step;
+        let mut r_new = z_new.li(n) - w;
+        let mut halvings = 0;
+        while !r_new.is_finite() || r_new.norm() >= r.norm() {
+            if halvings == 30 {
+                // the residual is dominated by the rounding errors of
+                // Li(n,z) only if the Newton step is small
+                return if newton.norm() <= 512.0*f64::EPSILON*z.norm() {
+                    z
+                } else {
+                    Complex::new(f64::NAN, f64::NAN)
+                };
+            }
+            halvings += 1;
+            step *= 0.5;
+            z_new = z - step;
+            r_new = z_new.li(n) - w;
+        }
+
+        z = z_new;
+        r = r_new;
+    }
+
+    Complex::new(f64::NAN, f64::NAN)
+}
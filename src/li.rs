@@ -1,10 +1,11 @@
 use num::complex::Complex;
-mod eta;
-mod fac;
-mod harmonic;
-mod zeta;
-mod cli;
-mod rli;
+pub(crate) mod eta;
+pub(crate) mod fac;
+pub(crate) mod harmonic;
+pub(crate) mod zeta;
+pub(crate) mod cli;
+pub(crate) mod table;
+pub(crate) mod rli;
 
 /// Provides the n-th order polylogarithm function `li()` of a number of type `T`.
 pub trait Li<T> {
@@ -51,3 +52,51 @@ impl Li<f64> for f64 {
         rli::rli(n, *self)
     }
 }
+
+/// Provides the n-th order polylogarithm function `li_exp()` of the
+/// exponential of a number of type `T`, i.e. `mu.li_exp(n)` returns
+/// Li(n,e^mu).
+///
+/// The argument mu = ln(z) is passed directly to the series expansion
+/// around z = 1 and to the inversion formula for large Re(mu), which
+/// avoids the loss of precision of `mu.exp().li(n)` for small |mu| and
+/// the overflow of e^mu for large Re(mu).
+pub trait LiExp<T> {
+    fn li_exp(&self, n: i32) -> T;
+}
+
+impl LiExp<Complex<f64>> for Complex<f64> {
+    /// Returns the complex n-th order polylogarithm Li(n,e^mu) of the
+    /// exponential of a complex number mu of type `Complex<f64>` for
+    /// all integers `n`.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiExp};
+    ///
+    /// let mu = Complex::new(0.5_f64, 1.0_f64);
+    /// assert!((mu.li_exp(10) - mu.exp().li(10)).norm() < 4.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_exp(&self, n: i32) -> Complex<f64> {
+        cli::cli_exp(n, *self)
+    }
+}
+
+impl LiExp<f64> for f64 {
+    /// Returns the real n-th order polylogarithm Re[Li(n,e^mu)] of the
+    /// exponential of a real number mu of type `f64` for all integers
+    /// `n`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::LiExp;
+    ///
+    /// // Li(1,e^mu) = -ln(1 - e^mu)
+    /// let mu = -1e-10_f64;
+    /// assert!((mu.li_exp(1) - 23.025850929990457_f64).abs() < 1e-14_f64);
+    /// ```
+    fn li_exp(&self, n: i32) -> f64 {
+        rli::rli_exp(n, *self)
+    }
+}
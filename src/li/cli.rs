@@ -1,9 +1,9 @@
 use num::complex::Complex;
-use crate::cln::CLn;
-use crate::{Li, Li0, Li1, Li2, Li3, Li4, Li5, Li6};
+use crate::cln::{CLn, expm1};
+use crate::{Li, LiExp, Li0, Li1, Li2, Li3, Li4, Li5, Li6};
 use super::eta::neg_eta;
 use super::fac::{fac, inv_fac};
-use super::harmonic::harmonic;
+use super::table::{Coeffs, Table};
 use super::zeta::zeta;
 
 /// returns complex n-th order polylogarithm Li(n,z) for complex z
@@ -19,14 +19,21 @@ pub fn cli(n: i32, z: Complex<f64>) -> Complex<f64> {
             let l = z.re.ln();
             Complex::new(z.re.li(n), -std::f64::consts::PI*inv_fac(n - 1)*l.powi(n - 1))
         }
+    } else if !(0..=6).contains(&n) && is_unit_circle(z) {
+        let t = z.arg();
+        if n < 0 {
+            li_unit_circle_neg(n, t)
+        } else {
+            li_unit_circle_pos(&n, t)
+        }
     } else if n < -1 {
         // arXiv:2010.09860
         let c = 4.0*std::f64::consts::PI*std::f64::consts::PI;
         let l2 = z.cln().norm_sqr();
         if c*z.norm_sqr() < l2 {
-            li_series(n, z)
+            li_series(&n, z)
         } else if l2 < 0.512*0.512*c {
-            li_unity_neg(n, z)
+            li_unity_neg(n, z.cln())
         } else {
             let sqrtz = z.sqrt();
             2.0_f64.powi(n - 1)*(cli(n, sqrtz) + cli(n, -sqrtz))
@@ -47,13 +54,126 @@ pub fn cli(n: i32, z: Complex<f64>) -> Complex<f64> {
         z.li5()
     } else if n == 6 {
         z.li6()
+    } else {
+        cli_pos(&n, z)
+    }
+}
+
+/// returns Li(n,z) for n > 6 and complex z with Im(z) != 0 and
+/// |z| != 1, given the coefficients of order n
+fn cli_pos<C: Coeffs>(c: &C, z: Complex<f64>) -> Complex<f64> {
+    let n = c.order();
+
+    if z.norm_sqr() <= 0.75*0.75 {
+        li_series(c, z)
+    } else if z.norm_sqr() >= 1.4*1.4 {
+        let sgn = if is_even(n) { -1.0 } else { 1.0 };
+        sgn*li_series(c, 1.0/z) + li_rest(n, (-z).cln())
+    } else {
+        let l = z.cln();
+        li_unity_pos(c, l, (-l).cln())
+    }
+}
+
+/// returns Li(n,z) for complex z, given the tabulated coefficients of
+/// order n
+pub fn cli_table(t: &Table, z: Complex<f64>) -> Complex<f64> {
+    let n = t.order();
+
+    if n <= 6 || z.is_nan() || z.is_infinite() || z.im == 0.0 {
+        cli(n, z)
+    } else if is_unit_circle(z) {
+        li_unit_circle_pos(t, z.arg())
+    } else {
+        cli_pos(t, z)
+    }
+}
+
+/// returns [Li(nmin,z), ..., Li(nmax,z)] for 6 < nmin <= nmax and
+/// complex z with Im(z) != 0, where the logarithms and 1/z are
+/// calculated only once for all orders
+pub fn cli_range(nmin: i32, nmax: i32, z: Complex<f64>) -> Vec<Complex<f64>> {
+    if is_unit_circle(z) {
+        let t = z.arg();
+        (nmin..=nmax).map(|n| li_unit_circle_pos(&n, t)).collect()
     } else if z.norm_sqr() <= 0.75*0.75 {
-        li_series(n, z)
+        (nmin..=nmax).map(|n| li_series(&n, z)).collect()
     } else if z.norm_sqr() >= 1.4*1.4 {
+        let zi = 1.0/z;
+        let lnz = (-z).cln();
+        (nmin..=nmax).map(|n| {
+            let sgn = if is_even(n) { -1.0 } else { 1.0 };
+            sgn*li_series(&n, zi) + li_rest(n, lnz)
+        }).collect()
+    } else {
+        let l = z.cln();
+        let lnml = (-l).cln();
+        (nmin..=nmax).map(|n| li_unity_pos(&n, l, lnml)).collect()
+    }
+}
+
+/// returns complex n-th order polylogarithm Li(n,e^mu) for complex mu
+pub fn cli_exp(n: i32, mu: Complex<f64>) -> Complex<f64> {
+    let pi = std::f64::consts::PI;
+
+    if mu.is_nan() || mu.im.is_infinite() {
+        return Complex::new(f64::NAN, f64::NAN);
+    } else if mu.re == f64::INFINITY {
+        return cli(n, Complex::new(f64::INFINITY, 0.0));
+    } else if mu.re == f64::NEG_INFINITY {
+        return Complex::new(0.0, 0.0);
+    }
+
+    // shift Im(mu) into (-pi,pi]
+    let mut mu = Complex::new(mu.re, mu.im - 2.0*pi*(0.5*mu.im/pi).round());
+    if mu.im <= -pi {
+        mu.im += 2.0*pi;
+    }
+
+    if mu.im == 0.0 {
+        if mu.re <= 0.0 || n <= 0 {
+            Complex::new(mu.re.li_exp(n), 0.0)
+        } else { // mu.re > 0.0 && n > 0
+            Complex::new(mu.re.li_exp(n), -pi*inv_fac(n - 1)*mu.re.powi(n - 1))
+        }
+    } else if !(0..=6).contains(&n) && mu.re == 0.0 {
+        if n < 0 {
+            li_unit_circle_neg(n, mu.im)
+        } else {
+            li_unit_circle_pos(&n, mu.im)
+        }
+    } else if n < -1 {
+        // arXiv:2010.09860
+        let c = 4.0*pi*pi;
+        if mu.re > 0.0 {
+            let sgn = if is_even(n) { -1.0 } else { 1.0 };
+            sgn*cli_exp(n, -mu)
+        } else if c*(2.0*mu.re).exp() < mu.norm_sqr() {
+            li_series(&n, mu.exp())
+        } else if mu.norm_sqr() < 0.512*0.512*c {
+            li_unity_neg(n, mu)
+        } else {
+            cli(n, mu.exp())
+        }
+    } else if n == -1 {
+        // Li(-1,z) = Li(-1,1/z)
+        let m = if mu.re > 0.0 { -mu } else { mu };
+        let li = m.exp()/(expm1(m)*expm1(m));
+        if li.is_finite() { li } else { li_pole(-1, m) }
+    } else if n == 0 {
+        let li = 1.0/expm1(-mu);
+        if li.is_finite() { li } else { li_pole(0, mu) }
+    } else if mu.re <= 0.75_f64.ln() {
+        cli(n, mu.exp())
+    } else if mu.re >= 1.4_f64.ln() {
+        // ln(-e^mu) with imaginary part in (-pi,pi]
+        let lnz = if mu.im > 0.0 { mu - Complex::new(0.0, pi) } else { mu + Complex::new(0.0, pi) };
         let sgn = if is_even(n) { -1.0 } else { 1.0 };
-        sgn*li_series(n, 1.0/z) + li_rest(n, z)
+        sgn*cli(n, (-mu).exp()) + li_rest(n, lnz)
+    } else if n == 1 {
+        -(-expm1(mu)).cln()
     } else {
-        li_unity_pos(n, z)
+        li_unity_pos(&n, mu, (-mu).cln())
     }
 }
 
@@ -62,12 +182,28 @@ fn is_even(x: i32) -> bool {
     x & 1 == 0
 }
 
+/// returns true if |z| = 1 within the floating point precision
+fn is_unit_circle(z: Complex<f64>) -> bool {
+    (z.norm_sqr() - 1.0).abs() <= 4.0*f64::EPSILON
+}
+
+/// returns i^k x
+fn ipow_mul(k: i32, x: f64) -> Complex<f64> {
+    match k & 3 {
+        0 => Complex::new(x, 0.0),
+        1 => Complex::new(0.0, x),
+        2 => Complex::new(-x, 0.0),
+        _ => Complex::new(0.0, -x),
+    }
+}
+
 /// returns r.h.s. of inversion formula for complex z
 ///
 /// Li(n,-z) + (-1)^n Li(n,-1/z)
 ///    = -ln(n,z)^n/n! + 2 sum(k=1:(n÷2), ln(z)^(n-2k)/(n-2k)! Li(2k,-1))
-fn li_rest(n: i32, z: Complex<f64>) -> Complex<f64> {
-    let lnz = (-z).cln();
+///
+/// The function takes lnz = ln(z) as argument.
+fn li_rest(n: i32, lnz: Complex<f64>) -> Complex<f64> {
     let lnz2 = lnz*lnz;
     let kmax = if is_even(n) { n/2 } else { (n - 1)/2 };
     let mut p = if is_even(n) { Complex::new(1.0, 0.0) } else { lnz };
@@ -95,47 +231,185 @@ fn li_rest(n: i32, z: Complex<f64>) -> Complex<f64> {
 /// zeta(1) = -ln(-ln(z)) + harmonic(n - 1)
 ///
 /// harmonic(n) = sum(k=1:n, 1/k)
-fn li_unity_pos(n: i32, z: Complex<f64>) -> Complex<f64> {
-    let l = z.cln();
-    let mut sum = Complex::new(zeta(n), 0.0);
+///
+/// The function takes the coefficients of order n, l = ln(z) and
+/// lnml = ln(-ln(z)) as arguments.
+fn li_unity_pos<C: Coeffs>(c: &C, l: Complex<f64>, lnml: Complex<f64>) -> Complex<f64> {
+    let n = c.order();
+    let mut sum = Complex::new(c.zeta(0), 0.0);
     let mut p = Complex::new(1.0, 0.0); // collects l^j/j!
 
     for j in 1..(n - 1) {
         p *= l/(j as f64);
         let old_sum = sum;
-        sum += zeta(n - j)*p;
+        sum += c.zeta(j)*p;
         if sum == old_sum { break; }
     }
 
     p *= l/((n - 1) as f64);
-    sum += (harmonic(n - 1) - (-l).cln())*p;
+    sum += (c.harmonic() - lnml)*p;
 
     p *= l/(n as f64);
-    sum += zeta(0)*p;
+    sum += c.zeta(n)*p;
 
     p *= l/((n + 1) as f64);
-    sum += zeta(-1)*p;
+    sum += c.zeta(n + 1)*p;
 
     let l2 = l*l;
 
     for j in ((n + 3)..i32::MAX).step_by(2) {
         p *= l2/(j - 1).checked_mul(j).map_or(std::f64::INFINITY, |i| i as f64);
         let old_sum = sum;
-        sum += zeta(n - j)*p;
+        sum += c.zeta(j)*p;
+        if sum == old_sum { break; }
+    }
+
+    sum
+}
+
+/// returns Li(n,e^(it)) for n > 1 and t in [-pi,pi] using the
+/// series expansion of Li(n,z) around z = 1 with ln(z) = it:
+///
+/// Li(n,e^(it)) = sum(j=0:Inf, zeta(n-j) (it)^j/j!)
+///
+/// where
+///
+/// zeta(1) = harmonic(n - 1) - ln|t| + i pi/2 sign(t)
+///
+/// The part of Li(n,e^(it)) proportional to i^n is a finite sum,
+/// which is equal to the Bernoulli polynomial
+///
+/// -(2pi i)^n/(2 n!) B_n(t/(2pi))   for t in [0,pi].
+///
+/// The other part is a Clausen-type function, whose series converges
+/// geometrically with ratio |t|/(2pi) <= 1/2.  The two parts are
+/// accumulated separately, such that they are not polluted by
+/// rounding errors of each other.
+fn li_unit_circle_pos<C: Coeffs>(c: &C, t: f64) -> Complex<f64> {
+    let n = c.order();
+    let mut sum = Complex::new(c.zeta(0), 0.0);
+    let mut p = 1.0; // collects t^j/j!
+
+    for j in 1..(n - 1) {
+        p *= t/(j as f64);
+        if p == 0.0 { return sum; }
+        sum += ipow_mul(j, c.zeta(j)*p);
+    }
+
+    p *= t/((n - 1) as f64);
+    sum += ipow_mul(n - 1, (c.harmonic() - t.abs().ln())*p);
+    sum += ipow_mul(n, 0.5*std::f64::consts::PI*t.signum()*p);
+
+    p *= t/(n as f64);
+    sum += ipow_mul(n, c.zeta(n)*p);
+
+    p *= t/((n + 1) as f64);
+    sum += ipow_mul(n + 1, c.zeta(n + 1)*p);
+
+    let t2 = t*t;
+
+    for j in ((n + 3)..i32::MAX).step_by(2) {
+        p *= t2/(j - 1).checked_mul(j).map_or(f64::INFINITY, |i| i as f64);
+        let old_sum = sum;
+        sum += ipow_mul(j, c.zeta(j)*p);
         if sum == old_sum { break; }
     }
 
     sum
 }
 
+/// returns Li(n,e^(it)) for n < 0 and t in [-pi,pi] using the
+/// series expansion for n < 0 and z ~ 1 with ln(z) = it:
+///
+/// Li(n,e^(it)) = gamma(1-n) (-it)^(n-1)
+///                + sum(k=0:Inf, zeta(n-k) (it)^k/k!)
+///
+/// All terms are proportional to i^(n+1), i.e. Li(n,e^(it)) is real
+/// for odd n and purely imaginary for even n.  For n < -10 the terms
+/// of the series cancel for |t| ~ pi and the sum
+///
+/// Li(n,e^(it)) = gamma(1-n) i^(n-1) sum(k=-Inf:Inf, (2 pi k - t)^(n-1))
+///
+/// is used instead, which converges rapidly for large |n|.
+fn li_unit_circle_neg(n: i32, t: f64) -> Complex<f64> {
+    if n < -10 {
+        return li_unit_circle_lattice(n, t);
+    }
+
+    let t2 = t*t;
+    let mut sum = ipow_mul(1 - n, fac(-n)*t.powi(n - 1));
+    let (mut k, mut tk) = if is_even(n) {
+        (1, t)
+    } else {
+        sum += zeta(n);
+        (2, t2)
+    };
+
+    loop {
+        let term = ipow_mul(k, zeta(n - k)*inv_fac(k)*tk);
+        if !term.is_finite() { break; }
+        let sum_old = sum;
+        sum += term;
+        if sum == sum_old || k >= i32::MAX - 2 { break; }
+        tk *= t2;
+        k += 2;
+    }
+
+    sum
+}
+
+/// returns Li(n,e^(it)) for n < -10 and t in [-pi,pi] from
+///
+/// Li(n,e^(it)) = gamma(1-n) i^(n-1) sum(k=-Inf:Inf, (2 pi k - t)^(n-1))
+///
+/// where the terms for k and -k are added pairwise, starting from
+/// the largest ones.
+fn li_unit_circle_lattice(n: i32, t: f64) -> Complex<f64> {
+    let tpi = 2.0*std::f64::consts::PI;
+    let mut sum = t.powi(n - 1);
+    if is_even(n) {
+        sum = -sum; // (-t)^(n-1)
+    }
+
+    for k in 1..i32::MAX {
+        let x = tpi*f64::from(k);
+        let sum_old = sum;
+        sum += (x - t).powi(n - 1) + (-x - t).powi(n - 1);
+        if sum == sum_old { break; }
+    }
+
+    ipow_mul(n - 1, fac(-n)*sum)
+}
+
+/// returns the pole term gamma(1-n) (-lnz)^(n-1) of Li(n,z) at z = 1
+/// for n <= 0; if the power overflows, it is calculated from the
+/// modulus and the phase of lnz, because |lnz|^2 may underflow
+pub fn li_pole(n: i32, lnz: Complex<f64>) -> Complex<f64> {
+    let p = fac(-n)*(-lnz).powi(n - 1);
+    if p.is_finite() {
+        return p;
+    }
+
+    let r = lnz.norm();
+    let m = fac(-n)*r.powi(n - 1);
+    let u = (-lnz/r).powi(n - 1);
+
+    // avoid inf*0 for vanishing components
+    Complex::new(
+        if u.re == 0.0 { 0.0 } else { m*u.re },
+        if u.im == 0.0 { 0.0 } else { m*u.im },
+    )
+}
+
 /// returns Li(n,x) using the series expansion for n < 0 and x ~ 1
 ///
 /// Li(n,x) = gamma(1-n) (-ln(x))^(n-1)
 ///           + sum(k=0:Inf, zeta(n-k) ln(x)^k/k!)
-fn li_unity_neg(n: i32, z: Complex<f64>) -> Complex<f64> {
-    let lnz = z.cln();
+///
+/// The function takes lnz = ln(x) as argument.
+fn li_unity_neg(n: i32, lnz: Complex<f64>) -> Complex<f64> {
     let lnz2 = lnz*lnz;
-    let mut sum = fac(-n)*(-lnz).powi(n - 1);
+    let mut sum = li_pole(n, lnz);
     let (mut k, mut lnzk) = if is_even(n) {
         (1, lnz)
     } else {
@@ -160,13 +434,15 @@ fn li_unity_neg(n: i32, z: Complex<f64>) -> Complex<f64> {
 /// for |x| < 1:
 ///
 /// Li(n,x) = sum(k=1:Inf, x^k/k^n)
-fn li_series(n: i32, z: Complex<f64>) -> Complex<f64>
+///
+/// The function takes the coefficients of order n as argument.
+fn li_series<C: Coeffs>(c: &C, z: Complex<f64>) -> Complex<f64>
 {
     let mut sum = z;
     let mut zn = z*z;
 
     for k in 2..i32::MAX {
-        let term = zn/(k as f64).powi(n);
+        let term = zn/c.pow(k);
         if !term.is_finite() { break; }
         let old_sum = sum;
         sum += term;
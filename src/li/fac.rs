@@ -158,6 +158,19 @@ pub fn inv_fac(n: i32) -> f64 {
     }
 }
 
+/// returns m^m e^(-m)/m! for m >= 0, where Stirling's series is used
+/// for m >= 20
+pub fn stirling_ratio(m: i32) -> f64 {
+    if m < 20 {
+        (1..=m).fold(1.0, |p, i| p*f64::from(m)/(std::f64::consts::E*f64::from(i)))
+    } else {
+        let x = f64::from(m);
+        let x2 = 1.0/(x*x);
+        let s = (1.0/12.0 - x2*(1.0/360.0 - x2*(1.0/1260.0 - x2*(1.0/1680.0 - x2/1188.0))))/x;
+        (-s).exp()/(2.0*std::f64::consts::PI*x).sqrt()
+    }
+}
+
 #[test]
 fn test_inv_fac() {
     assert!(inv_fac(  0) == 1.0);
@@ -172,3 +185,12 @@ fn test_inv_fac() {
 fn test_inv_fac_panic() {
     inv_fac(-1);
 }
+
+#[test]
+fn test_stirling_ratio() {
+    assert!(stirling_ratio(0) == 1.0);
+    assert!((stirling_ratio(1)/0.36787944117144233 - 1.0).abs() < 4e-15);
+    assert!((stirling_ratio(19)/0.091123132468412291 - 1.0).abs() < 4e-15);
+    assert!((stirling_ratio(20)/0.088835317392085218 - 1.0).abs() < 4e-15);
+    assert!((stirling_ratio(1000)/0.0126146113487215 - 1.0).abs() < 4e-15);
+}
@@ -1,9 +1,10 @@
 use num::complex::Complex;
 use crate::cln::CLn;
 use crate::{Li0, Li1, Li2, Li3, Li4};
+use super::cli::li_pole;
 use super::eta::neg_eta;
-use super::fac::{fac, inv_fac};
-use super::harmonic::harmonic;
+use super::fac::inv_fac;
+use super::table::{Coeffs, Table};
 use super::zeta::zeta;
 
 /// returns real n-th order polylogarithm Re[Li(n,x)] for real x
@@ -23,11 +24,11 @@ pub fn rli(n: i32, x: f64) -> f64 {
         let c = 4.0*std::f64::consts::PI*std::f64::consts::PI;
         let l2 = ln_sqr(x);
         if c*x*x < l2 {
-            li_series(n, x)
+            li_series(&n, x)
         } else if l2 < 0.512*0.512*c {
-            li_unity_neg(n, Complex::new(x, 0.0)).re
+            li_unity_neg(n, Complex::new(x, 0.0).cln()).re
         } else {
-            odd_sgn(n)*li_series(n, x.recip())
+            odd_sgn(n)*li_series(&n, x.recip())
         }
     } else if n == -1 {
         x/((1.0 - x)*(1.0 - x))
@@ -42,20 +43,86 @@ pub fn rli(n: i32, x: f64) -> f64 {
     } else if n == 4 {
         x.li4()
     } else {
-        // transform x to y in [-1,1]
-        let (y, rest, sgn) = if x < -1.0 {
-            (x.recip(), li_neg_rest(n, x), odd_sgn(n))
-        } else if x < 1.0 {
-            (x, 0.0, 1.0)
-        } else { // x > 1.0
-            (x.recip(), li_pos_rest(n, x), odd_sgn(n))
-        };
-
-        if n < 20 && y > 0.75 {
-            sgn*li_unity_pos(n, y) + rest
+        rli_pos(&n, x)
+    }
+}
+
+/// returns Re[Li(n,x)] for n > 4 and real x with x != 0 and |x| != 1,
+/// given the coefficients of order n
+fn rli_pos<C: Coeffs>(c: &C, x: f64) -> f64 {
+    let odd_sgn = |n| if is_even(n) { -1.0 } else { 1.0 };
+    let n = c.order();
+
+    // transform x to y in [-1,1]
+    let (y, rest, sgn) = if x < -1.0 {
+        (x.recip(), li_neg_rest(n, x), odd_sgn(n))
+    } else if x < 1.0 {
+        (x, 0.0, 1.0)
+    } else { // x > 1.0
+        (x.recip(), li_pos_rest(n, x.ln()), odd_sgn(n))
+    };
+
+    if n < 20 && y > 0.75 {
+        sgn*li_unity_pos(c, y.ln()) + rest
+    } else {
+        sgn*li_series(c, y) + rest
+    }
+}
+
+/// returns Re[Li(n,x)] for real x, given the tabulated coefficients
+/// of order n
+pub fn rli_table(t: &Table, x: f64) -> f64 {
+    let n = t.order();
+
+    if n <= 4 || x == 0.0 || x == 1.0 || x == -1.0 || x.is_nan() {
+        rli(n, x)
+    } else {
+        rli_pos(t, x)
+    }
+}
+
+/// returns real n-th order polylogarithm Re[Li(n,e^mu)] for real mu
+pub fn rli_exp(n: i32, mu: f64) -> f64 {
+    let odd_sgn = |n| if is_even(n) { -1.0 } else { 1.0 };
+
+    if mu.is_nan() {
+        f64::NAN
+    } else if mu == 0.0 {
+        zeta(n)
+    } else if mu == f64::NEG_INFINITY {
+        0.0
+    } else if mu == f64::INFINITY {
+        if n > 0 { f64::NEG_INFINITY } else if n == 0 { -1.0 } else { 0.0 }
+    } else if n < -1 {
+        // arXiv:2010.09860
+        let c = 4.0*std::f64::consts::PI*std::f64::consts::PI;
+        if mu > 0.0 {
+            odd_sgn(n)*rli_exp(n, -mu)
+        } else if c*(2.0*mu).exp() < mu*mu {
+            li_series(&n, mu.exp())
         } else {
-            sgn*li_series(n, y) + rest
+            li_unity_neg(n, Complex::new(mu, 0.0)).re
         }
+    } else if n == -1 {
+        // Li(-1,x) = Li(-1,1/x)
+        let m = -mu.abs();
+        m.exp()/(m.exp_m1()*m.exp_m1())
+    } else if n == 0 {
+        1.0/(-mu).exp_m1()
+    } else if n == 1 {
+        if mu < -std::f64::consts::LN_2 {
+            -(-mu.exp()).ln_1p()
+        } else if mu < 1.0 {
+            -mu.exp_m1().abs().ln()
+        } else {
+            -(mu + (-(-mu).exp()).ln_1p())
+        }
+    } else if mu <= 0.75_f64.ln() {
+        rli(n, mu.exp())
+    } else if mu >= 1.4_f64.ln() {
+        odd_sgn(n)*rli(n, (-mu).exp()) + li_pos_rest(n, mu)
+    } else {
+        li_unity_pos(&n, mu)
     }
 }
 
@@ -122,12 +189,11 @@ fn next_cosi((sn, cn): (f64, f64), (s2, c2): (f64, f64)) -> (f64, f64) {
     (sn*c2 + cn*s2, cn*c2 - sn*s2)
 }
 
-/// returns r.h.s. of inversion formula for x > 1;
+/// returns r.h.s. of inversion formula for x > 1, given l = ln(x);
 /// same expression as in li_neg_rest(n,x), but with
 /// complex logarithm ln(-x)
-fn li_pos_rest(n: i32, x: f64) -> f64 {
+fn li_pos_rest(n: i32, l: f64) -> f64 {
     let pi = std::f64::consts::PI;
-    let l = x.ln();
     let mag = l.hypot(pi); // |ln(-x)|
     let arg = pi.atan2(l); // arg(ln(-x))
     let l2 = mag*mag;      // |ln(-x)|^2
@@ -172,31 +238,34 @@ fn li_pos_rest(n: i32, x: f64) -> f64 {
 /// zeta(1) = -ln(-ln(x)) + harmonic(n - 1)
 ///
 /// harmonic(n) = sum(k=1:n, 1/k)
-fn li_unity_pos(n: i32, x: f64) -> f64 {
-    let l = x.ln();
-    let mut sum = zeta(n);
+///
+/// The function takes the coefficients of order n and l = ln(x) as
+/// arguments.  For l > 0 the real part Re[Li(n,x)] is returned.
+fn li_unity_pos<C: Coeffs>(c: &C, l: f64) -> f64 {
+    let n = c.order();
+    let mut sum = c.zeta(0);
     let mut p = 1.0; // collects l^j/j!
 
     for j in 1..(n - 1) {
         p *= l/(j as f64);
-        sum += zeta(n - j)*p;
+        sum += c.zeta(j)*p;
     }
 
     p *= l/((n - 1) as f64);
-    sum += (harmonic(n - 1) - (-l).ln())*p;
+    sum += (c.harmonic() - l.abs().ln())*p;
 
     p *= l/(n as f64);
-    sum += zeta(0)*p;
+    sum += c.zeta(n)*p;
 
     p *= l/((n + 1) as f64);
-    sum += zeta(-1)*p;
+    sum += c.zeta(n + 1)*p;
 
     let l2 = l*l;
 
     for j in ((n + 3)..i32::MAX).step_by(2) {
         p *= l2/(((j - 1)*j) as f64);
         let old_sum = sum;
-        sum += zeta(n - j)*p;
+        sum += c.zeta(j)*p;
         if sum == old_sum { break; }
     }
 
@@ -207,10 +276,11 @@ fn li_unity_pos(n: i32, x: f64) -> f64 {
 ///
 /// Li(n,x) = gamma(1-n) (-ln(x))^(n-1)
 ///           + sum(k=0:Inf, zeta(n-k) ln(x)^k/k!)
-fn li_unity_neg(n: i32, z: Complex<f64>) -> Complex<f64> {
-    let lnz = z.cln();
+///
+/// The function takes lnz = ln(x) as argument.
+fn li_unity_neg(n: i32, lnz: Complex<f64>) -> Complex<f64> {
     let lnz2 = lnz*lnz;
-    let mut sum = fac(-n)*(-lnz).powi(n - 1);
+    let mut sum = li_pole(n, lnz);
     let (mut k, mut lnzk) = if is_even(n) {
         (1, lnz)
     } else {
@@ -235,13 +305,15 @@ fn li_unity_neg(n: i32, z: Complex<f64>) -> Complex<f64> {
 /// for |x| < 1:
 ///
 /// Li(n,x) = sum(k=1:Inf, x^k/k^n)
-fn li_series(n: i32, x: f64) -> f64
+///
+/// The function takes the coefficients of order n as argument.
+fn li_series<C: Coeffs>(c: &C, x: f64) -> f64
 {
     let mut sum = x;
     let mut xn = x*x;
 
     for k in 2..i32::MAX {
-        let term = xn/(k as f64).powi(n);
+        let term = xn/c.pow(k);
         if !term.is_finite() { break; }
         let old_sum = sum;
         sum += term;
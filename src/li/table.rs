@@ -0,0 +1,99 @@
+use super::harmonic::harmonic;
+use super::zeta::zeta;
+
+/// number of tabulated powers k^n
+const N_POW: usize = 128;
+
+/// number of tabulated zeta values zeta(-k) for k >= 0, which covers
+/// all finite zeta(-k)
+const N_ZETA: i32 = 264;
+
+/// largest tabulated argument of zeta(s); for s > S_ZETA one has
+/// zeta(s) = 1 in double precision
+const S_ZETA: i32 = 64;
+
+/// Provides the order-dependent coefficients of the series expansions
+/// of Li(n,z) for n > 0
+pub(crate) trait Coeffs {
+    /// returns the order n
+    fn order(&self) -> i32;
+    /// returns k^n
+    fn pow(&self, k: i32) -> f64;
+    /// returns harmonic(n - 1)
+    fn harmonic(&self) -> f64;
+    /// returns zeta(n - j) for j >= 0
+    fn zeta(&self, j: i32) -> f64;
+}
+
+/// the coefficients are calculated on the fly
+impl Coeffs for i32 {
+    fn order(&self) -> i32 {
+        *self
+    }
+
+    fn pow(&self, k: i32) -> f64 {
+        (k as f64).powi(*self)
+    }
+
+    fn harmonic(&self) -> f64 {
+        harmonic(*self - 1)
+    }
+
+    fn zeta(&self, j: i32) -> f64 {
+        zeta(*self - j)
+    }
+}
+
+/// coefficients tabulated once for a fixed order n > 1, which are
+/// identical to the ones calculated on the fly
+///
+/// Only the values zeta(s) with -N_ZETA <= s <= min(n,S_ZETA) are
+/// tabulated, such that the size of the table does not grow with n.
+pub(crate) struct Table {
+    n: i32,
+    harmonic: f64,
+    pow: Vec<f64>,
+    zeta: Vec<f64>, // zeta(s) for s = smax, smax - 1, ..., -N_ZETA
+    smax: i32,
+}
+
+impl Table {
+    pub(crate) fn new(n: i32) -> Table {
+        let smax = n.min(S_ZETA);
+        Table {
+            n,
+            harmonic: if n > 1 { harmonic(n - 1) } else { f64::NAN },
+            pow: if n > 1 { (0..N_POW).map(|k| (k as f64).powi(n)).collect() } else { vec![] },
+            zeta: if n > 1 { (-N_ZETA..=smax).rev().map(zeta).collect() } else { vec![] },
+            smax,
+        }
+    }
+}
+
+impl Coeffs for Table {
+    fn order(&self) -> i32 {
+        self.n
+    }
+
+    fn pow(&self, k: i32) -> f64 {
+        match self.pow.get(k as usize) {
+            Some(p) => *p,
+            None => (k as f64).powi(self.n),
+        }
+    }
+
+    fn harmonic(&self) -> f64 {
+        self.harmonic
+    }
+
+    fn zeta(&self, j: i32) -> f64 {
+        let s = self.n - j;
+        if s > self.smax {
+            return zeta(s);
+        }
+        match self.smax.checked_sub(s).and_then(|i| self.zeta.get(i as usize)) {
+            Some(z) => *z,
+            None => zeta(s),
+        }
+    }
+}
@@ -71,24 +71,26 @@ impl Li2Approx<Complex<f32>> for Complex<f32> {
     }
 }
 
+// bf[1..N-1] are the even Bernoulli numbers / (2 n + 1)!
+// generated by: Table[BernoulliB[2 n]/(2 n + 1)!, {n, 1, 19}]
+const BF: [f64; 10] = [
+    -1.0_f64/4.0_f64,
+     1.0_f64/36.0_f64,
+    -1.0_f64/3600.0_f64,
+     1.0_f64/211680.0_f64,
+    -1.0_f64/10886400.0_f64,
+     1.0_f64/526901760.0_f64,
+    -4.0647616451442255e-11_f64,
+     8.9216910204564526e-13_f64,
+    -1.9939295860721076e-14_f64,
+     4.5189800296199182e-16_f64,
+];
+
 impl Li2Approx<Complex<f64>> for Complex<f64> {
     /// series approximation of Li2(z) for Re(z) <= 1/2 and |z| <= 1
     /// in terms of self = -ln(1 - z)
     fn approx(&self) -> Complex<f64> {
-        // bf[1..N-1] are the even Bernoulli numbers / (2 n + 1)!
-        // generated by: Table[BernoulliB[2 n]/(2 n + 1)!, {n, 1, 19}]
-        let bf = [
-            -1.0_f64/4.0_f64,
-             1.0_f64/36.0_f64,
-            -1.0_f64/3600.0_f64,
-             1.0_f64/211680.0_f64,
-            -1.0_f64/10886400.0_f64,
-             1.0_f64/526901760.0_f64,
-            -4.0647616451442255e-11_f64,
-             8.9216910204564526e-13_f64,
-            -1.9939295860721076e-14_f64,
-             4.5189800296199182e-16_f64,
-        ];
+        let bf = BF;
         let x = *self;
         let x2 = x*x;
         let x4 = x2*x2;
@@ -106,6 +108,27 @@ impl Li2Approx<Complex<f64>> for Complex<f64> {
     }
 }
 
+/// returns the series approximations (Li2(z), Li2(z/(z-1))) for
+/// Re(z) <= 1/2 and |z| <= 1 in terms of x = -ln(1 - z), where the
+/// second one is the series at -x
+pub(crate) fn cli2_approx_pair(x: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let bf = BF;
+    let x2 = x*x;
+    let x4 = x2*x2;
+
+    let even = bf[0]*x2;
+    let odd = x + x*x2*(bf[1] +
+                        x2*(
+                            bf[2] +
+                            x2*bf[3] +
+                            x4*(bf[4] + x2*bf[5]) +
+                            x4*x4*(bf[6] + x2*bf[7] + x4*(bf[8] + x2*bf[9]))
+                        )
+    );
+
+    (even + odd, even - odd)
+}
+
 /// Provides the 2nd order polylogarithm (dilogarithm) function
 /// `li2()` of a number of type `T`.
 pub trait Li2<T> {
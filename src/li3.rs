@@ -115,8 +115,6 @@ impl Li3<Complex<f64>> for Complex<f64> {
     /// ```
     fn li3(&self) -> Complex<f64> {
         let pi  = std::f64::consts::PI;
-        let z2  = 1.6449340668482264;
-        let z3  = 1.2020569031595943;
 
         if self.im == 0.0 {
             if self.re <= 1.0 {
@@ -131,39 +129,56 @@ impl Li3<Complex<f64>> for Complex<f64> {
             let lnz = nz.ln();
 
             if lnz*lnz + pz*pz < 1.0 { // |log(z)| < 1
-                let u  = Complex::new(lnz, pz);
-                let u2 = u*u;
-                let u4 = u2*u2;
-                let u8 = u4*u4;
-                let c0 = z3 + u*(z2 - u2/12.0);
-                let c1 = 0.25 * (3.0 - 2.0*(-u).cln());
-
-                let cs = [
-                    -3.4722222222222222e-03, 1.1574074074074074e-05,
-                    -9.8418997228521038e-08, 1.1482216343327454e-09,
-                    -1.5815724990809166e-11, 2.4195009792525152e-13,
-                    -3.9828977769894877e-15
-                ];
-
-                c0 +
-                c1*u2 +
-                u4*(cs[0] + u2*cs[1]) +
-                u8*(cs[2] + u2*cs[3] + u4*(cs[4] + u2*cs[5])) +
-                u8*u8*cs[6]
+                let u = Complex::new(lnz, pz);
+                cli3_unity(u, (-u).cln())
             } else if nz <= 1.0 {
                 cli3_unit_circle(-(1.0 - self).cln())
             } else { // nz > 1
                 let arg = if pz > 0.0 { pz - pi } else { pz + pi };
                 let lmz = Complex::new(lnz, arg); // (-self).cln()
-                cli3_unit_circle(-(1.0 - 1.0/self).cln()) - lmz*(lmz*lmz/6.0 + z2)
+                cli3_inverse(-(1.0 - 1.0/self).cln(), lmz)
             }
         }
     }
 }
 
+/// series expansion of Li3(z) for |ln(z)| < 1 in terms of u = ln(z),
+/// where lnmu = ln(-u)
+pub(crate) fn cli3_unity(u: Complex<f64>, lnmu: Complex<f64>) -> Complex<f64> {
+    let z2  = 1.6449340668482264;
+    let z3  = 1.2020569031595943;
+
+    let u2 = u*u;
+    let u4 = u2*u2;
+    let u8 = u4*u4;
+    let c0 = z3 + u*(z2 - u2/12.0);
+    let c1 = 0.25 * (3.0 - 2.0*lnmu);
+
+    let cs = [
+        -3.4722222222222222e-03, 1.1574074074074074e-05,
+        -9.8418997228521038e-08, 1.1482216343327454e-09,
+        -1.5815724990809166e-11, 2.4195009792525152e-13,
+        -3.9828977769894877e-15
+    ];
+
+    c0 +
+    c1*u2 +
+    u4*(cs[0] + u2*cs[1]) +
+    u8*(cs[2] + u2*cs[3] + u4*(cs[4] + u2*cs[5])) +
+    u8*u8*cs[6]
+}
+
+/// Li3(z) for |z| > 1 from the inversion formula in terms of
+/// x = -ln(1 - 1/z) and lmz = ln(-z)
+pub(crate) fn cli3_inverse(x: Complex<f64>, lmz: Complex<f64>) -> Complex<f64> {
+    let z2  = 1.6449340668482264;
+
+    cli3_unit_circle(x) - lmz*(lmz*lmz/6.0 + z2)
+}
+
 /// series approximation of Li3(z) for |z| <= 1
 /// in terms of x = -ln(1 - z)
-fn cli3_unit_circle(x: Complex<f64>) -> Complex<f64> {
+pub(crate) fn cli3_unit_circle(x: Complex<f64>) -> Complex<f64> {
     let bf  = [
         1.0, -3.0/8.0, 17.0/216.0, -5.0/576.0,
         1.2962962962962963e-04,  8.1018518518518519e-05,
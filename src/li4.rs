@@ -154,8 +154,6 @@ impl Li4<Complex<f64>> for Complex<f64> {
     /// ```
     fn li4(&self) -> Complex<f64> {
         let pi  = std::f64::consts::PI;
-        let pi2 = pi*pi;
-        let z4  = 1.0823232337111382;
 
         if self.im == 0.0 {
             if self.re <= 1.0 {
@@ -170,45 +168,62 @@ impl Li4<Complex<f64>> for Complex<f64> {
             let lnz = nz.ln();
 
             if lnz*lnz + pz*pz < 1.0 { // |log(z)| < 1
-                let u  = Complex::new(lnz, pz);
-                let u2 = u*u;
-                let u4 = u2*u2;
-                let u8 = u4*u4;
-                let c1 = 1.2020569031595943; // zeta(3)
-                let c2 = 0.82246703342411322;
-                let c3 = (11.0/6.0 - (-u).cln())/6.0;
-                let c4 = -1.0/48.0;
-
-                let cs = [
-                    -6.9444444444444444e-04, 1.6534391534391534e-06,
-                    -1.0935444136502338e-08, 1.0438378493934049e-10,
-                    -1.2165942300622435e-12, 1.6130006528350101e-14,
-                    -2.3428810452879340e-16
-                ];
-
-                z4 + u2*(c2 + u2*c4) +
-                u*(c1 +
-                   c3*u2 +
-                   u4*(cs[0] + u2*cs[1]) +
-                   u8*(cs[2] + u2*cs[3] + u4*(cs[4] + u2*cs[5])) +
-                   u8*u8*cs[6]
-                )
+                let u = Complex::new(lnz, pz);
+                cli4_unity(u, (-u).cln())
             } else if nz <= 1.0 {
                 cli4_unit_circle(-(1.0 - self).cln())
             } else { // nz > 1.0
-                let pi4  = pi2*pi2;
                 let arg = if pz > 0.0 { pz - pi } else { pz + pi };
                 let lmz = Complex::new(lnz, arg); // (-self).cln()
-                let lmz2 = lmz*lmz;
-                -cli4_unit_circle(-(1.0 - 1.0/self).cln()) + 1.0/360.0*(-7.0*pi4 + lmz2*(-30.0*pi2 - 15.0*lmz2))
+                cli4_inverse(-(1.0 - 1.0/self).cln(), lmz)
             }
         }
     }
 }
 
+/// series expansion of Li4(z) for |ln(z)| < 1 in terms of u = ln(z),
+/// where lnmu = ln(-u)
+pub(crate) fn cli4_unity(u: Complex<f64>, lnmu: Complex<f64>) -> Complex<f64> {
+    let z4  = 1.0823232337111382;
+
+    let u2 = u*u;
+    let u4 = u2*u2;
+    let u8 = u4*u4;
+    let c1 = 1.2020569031595943; // zeta(3)
+    let c2 = 0.82246703342411322;
+    let c3 = (11.0/6.0 - lnmu)/6.0;
+    let c4 = -1.0/48.0;
+
+    let cs = [
+        -6.9444444444444444e-04, 1.6534391534391534e-06,
+        -1.0935444136502338e-08, 1.0438378493934049e-10,
+        -1.2165942300622435e-12, 1.6130006528350101e-14,
+        -2.3428810452879340e-16
+    ];
+
+    z4 + u2*(c2 + u2*c4) +
+    u*(c1 +
+       c3*u2 +
+       u4*(cs[0] + u2*cs[1]) +
+       u8*(cs[2] + u2*cs[3] + u4*(cs[4] + u2*cs[5])) +
+       u8*u8*cs[6]
+    )
+}
+
+/// Li4(z) for |z| > 1 from the inversion formula in terms of
+/// x = -ln(1 - 1/z) and lmz = ln(-z)
+pub(crate) fn cli4_inverse(x: Complex<f64>, lmz: Complex<f64>) -> Complex<f64> {
+    let pi  = std::f64::consts::PI;
+    let pi2 = pi*pi;
+    let pi4  = pi2*pi2;
+    let lmz2 = lmz*lmz;
+
+    -cli4_unit_circle(x) + 1.0/360.0*(-7.0*pi4 + lmz2*(-30.0*pi2 - 15.0*lmz2))
+}
+
 /// series approximation of Li4(z) for |z| <= 1
 /// in terms of x = -ln(1 - z)
-fn cli4_unit_circle(x: Complex<f64>) -> Complex<f64> {
+pub(crate) fn cli4_unit_circle(x: Complex<f64>) -> Complex<f64> {
     let bf  = [
         1.0                   , -7.0/16.0              ,
         1.1651234567901235e-01, -1.9820601851851852e-02,
@@ -1,6 +1,8 @@
 use num::complex::Complex;
 use crate::cln::CLn;
 
+const Z5: f64 = 1.0369277551433699; // zeta(5)
+
 /// Provides the 5-th order polylogarithm function `li5()` of a
 /// number of type `T`.
 pub trait Li5<T> {
@@ -20,59 +22,72 @@ impl Li5<Complex<f64>> for Complex<f64> {
     /// ```
     fn li5(&self) -> Complex<f64> {
         let pi  = std::f64::consts::PI;
-        let pi2 = pi*pi;
-        let z5  = 1.0369277551433699; // zeta(5)
 
         if self.im == 0.0 && self.re == 0.0 {
             *self
         } else if self.im == 0.0 && self.re == 1.0 {
-            Complex::new(z5, self.im)
+            Complex::new(Z5, self.im)
         } else if self.im == 0.0 && self.re == -1.0 {
-            Complex::new(-15.0/16.0*z5, self.im)
+            Complex::new(-15.0/16.0*Z5, self.im)
         } else {
             let nz  = self.norm();
             let pz  = self.arg();
             let lnz = nz.ln();
 
             if lnz*lnz + pz*pz < 1.0 { // |log(z)| < 1
-                let u  = Complex::new(lnz, pz);
-                let u2 = u*u;
-                let c1 = 1.0823232337111382; // zeta(4)
-                let c2 = 0.60102845157979714; // zeta(3)/2
-                let c3 = 0.27415567780803774;
-                let c4 = (25.0/12.0 - (-u).cln())/24.0;
-                let c5 = -1.0/240.0;
-                let cs = [
-                    -1.1574074074074074e-04, 2.0667989417989418e-07,
-                    -1.0935444136502338e-09, 8.6986487449450412e-12,
-                    -8.6899587861588824e-14, 1.0081254080218813e-15
-                ];
-
-                z5 + u * c1 +
-                u2 * (c2 + u * c3 +
-                u2 * (c4 + u * c5 +
-                u2 * (cs[0] +
-                u2 * (cs[1] +
-                u2 * (cs[2] +
-                u2 * (cs[3] +
-                u2 * (cs[4] +
-                u2 * (cs[5]))))))))
+                let u = Complex::new(lnz, pz);
+                cli5_unity(u, (-u).cln())
             } else if nz <= 1.0 {
                 cli5_unit_circle(-(1.0 - self).cln())
             } else { // nz > 1.0
-                let pi4  = pi2*pi2;
                 let arg = if pz > 0.0 { pz - pi } else { pz + pi };
                 let lmz = Complex::new(lnz, arg); // (-self).cln()
-                let lmz2 = lmz*lmz;
-                cli5_unit_circle(-(1.0 - 1.0/self).cln()) - 1.0/360.0*lmz*(7.0*pi4 + lmz2*(10.0*pi2 + 3.0*lmz2))
+                cli5_inverse(-(1.0 - 1.0/self).cln(), lmz)
             }
         }
     }
 }
 
+/// series expansion of Li5(z) for |ln(z)| < 1 in terms of u = ln(z),
+/// where lnmu = ln(-u)
+pub(crate) fn cli5_unity(u: Complex<f64>, lnmu: Complex<f64>) -> Complex<f64> {
+    let u2 = u*u;
+    let c1 = 1.0823232337111382; // zeta(4)
+    let c2 = 0.60102845157979714; // zeta(3)/2
+    let c3 = 0.27415567780803774;
+    let c4 = (25.0/12.0 - lnmu)/24.0;
+    let c5 = -1.0/240.0;
+    let cs = [
+        -1.1574074074074074e-04, 2.0667989417989418e-07,
+        -1.0935444136502338e-09, 8.6986487449450412e-12,
+        -8.6899587861588824e-14, 1.0081254080218813e-15
+    ];
+
+    Z5 + u * c1 +
+    u2 * (c2 + u * c3 +
+    u2 * (c4 + u * c5 +
+    u2 * (cs[0] +
+    u2 * (cs[1] +
+    u2 * (cs[2] +
+    u2 * (cs[3] +
+    u2 * (cs[4] +
+    u2 * (cs[5]))))))))
+}
+
+/// Li5(z) for |z| > 1 from the inversion formula in terms of
+/// x = -ln(1 - 1/z) and lmz = ln(-z)
+pub(crate) fn cli5_inverse(x: Complex<f64>, lmz: Complex<f64>) -> Complex<f64> {
+    let pi  = std::f64::consts::PI;
+    let pi2 = pi*pi;
+    let pi4  = pi2*pi2;
+    let lmz2 = lmz*lmz;
+
+    cli5_unit_circle(x) - 1.0/360.0*lmz*(7.0*pi4 + lmz2*(10.0*pi2 + 3.0*lmz2))
+}
+
 /// series approximation of Li5(z) for |z| <= 1
 /// in terms of x = -ln(1 - z)
-fn cli5_unit_circle(x: Complex<f64>) -> Complex<f64> {
+pub(crate) fn cli5_unit_circle(x: Complex<f64>) -> Complex<f64> {
     let bf  = [
         1.0                   , -15.0/32.0             ,
         1.3953189300411523e-01, -2.8633777006172840e-02,
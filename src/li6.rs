@@ -1,6 +1,8 @@
 use num::complex::Complex;
 use crate::cln::CLn;
 
+const Z6: f64 = 1.0173430619844491; // zeta(6)
+
 /// Provides the 6-th order polylogarithm function `li6()` of a
 /// number of type `T`.
 pub trait Li6<T> {
@@ -20,61 +22,74 @@ impl Li6<Complex<f64>> for Complex<f64> {
     /// ```
     fn li6(&self) -> Complex<f64> {
         let pi  = std::f64::consts::PI;
-        let pi2 = pi*pi;
-        let z6  = 1.0173430619844491; // zeta(6)
 
         if self.im == 0.0 && self.re == 0.0 {
             *self
         } else if self.im == 0.0 && self.re == 1.0 {
-            Complex::new(z6, self.im)
+            Complex::new(Z6, self.im)
         } else if self.im == 0.0 && self.re == -1.0 {
-            Complex::new(-31.0/32.0*z6, self.im)
+            Complex::new(-31.0/32.0*Z6, self.im)
         } else {
             let nz  = self.norm();
             let pz  = self.arg();
             let lnz = nz.ln();
 
             if lnz*lnz + pz*pz < 1.0 { // |log(z)| < 1
-                let u  = Complex::new(lnz, pz);
-                let u2 = u*u;
-                let c1 = 1.0369277551433699; // zeta(5)
-                let c2 = 0.54116161685556910;
-                let c3 = 0.20034281719326571;
-                let c4 = 0.068538919452009435;
-                let c5 = (137.0/60.0 - (-u).cln())/120.0;
-                let c6 = -1.0/1440.0;
-                let cs = [
-                    -1.6534391534391534e-05, 2.2964432686654909e-08,
-                    -9.9413128513657614e-11, 6.6912682653423394e-13,
-                    -5.7933058574392549e-15
-                ];
-
-                z6 + u * c1 +
-                u2 * (c2 + u * c3 +
-                u2 * (c4 + u * c5 +
-                u2 * (c6 +
-                u * (cs[0] +
-                u2 * (cs[1] +
-                u2 * (cs[2] +
-                u2 * (cs[3] +
-                u2 * (cs[4]))))))))
+                let u = Complex::new(lnz, pz);
+                cli6_unity(u, (-u).cln())
             } else if nz <= 1.0 {
                 cli6_unit_circle(-(1.0 - self).cln())
             } else { // nz > 1.0
-                let pi4 = pi2*pi2;
-                let pi6 = pi2*pi4;
                 let arg = if pz > 0.0 { pz - pi } else { pz + pi };
                 let lmz = Complex::new(lnz, arg); // (-self).cln()
-                let lmz2 = lmz*lmz;
-                -cli6_unit_circle(-(1.0 - 1.0/self).cln()) - 31.0*pi6/15120.0 + lmz2*(-7.0/720.0*pi4 + lmz2*(-1.0/144.0*pi2 - 1.0/720.0*lmz2))
+                cli6_inverse(-(1.0 - 1.0/self).cln(), lmz)
             }
         }
     }
 }
 
+/// series expansion of Li6(z) for |ln(z)| < 1 in terms of u = ln(z),
+/// where lnmu = ln(-u)
+pub(crate) fn cli6_unity(u: Complex<f64>, lnmu: Complex<f64>) -> Complex<f64> {
+    let u2 = u*u;
+    let c1 = 1.0369277551433699; // zeta(5)
+    let c2 = 0.54116161685556910;
+    let c3 = 0.20034281719326571;
+    let c4 = 0.068538919452009435;
+    let c5 = (137.0/60.0 - lnmu)/120.0;
+    let c6 = -1.0/1440.0;
+    let cs = [
+        -1.6534391534391534e-05, 2.2964432686654909e-08,
+        -9.9413128513657614e-11, 6.6912682653423394e-13,
+        -5.7933058574392549e-15
+    ];
+
+    Z6 + u * c1 +
+    u2 * (c2 + u * c3 +
+    u2 * (c4 + u * c5 +
+    u2 * (c6 +
+    u * (cs[0] +
+    u2 * (cs[1] +
+    u2 * (cs[2] +
+    u2 * (cs[3] +
+    u2 * (cs[4]))))))))
+}
+
+/// Li6(z) for |z| > 1 from the inversion formula in terms of
+/// x = -ln(1 - 1/z) and lmz = ln(-z)
+pub(crate) fn cli6_inverse(x: Complex<f64>, lmz: Complex<f64>) -> Complex<f64> {
+    let pi  = std::f64::consts::PI;
+    let pi2 = pi*pi;
+    let pi4 = pi2*pi2;
+    let pi6 = pi2*pi4;
+    let lmz2 = lmz*lmz;
+
+    -cli6_unit_circle(x) - 31.0*pi6/15120.0 + lmz2*(-7.0/720.0*pi4 + lmz2*(-1.0/144.0*pi2 - 1.0/720.0*lmz2))
+}
+
 /// series approximation of Li6(z) for |z| <= 1
 /// in terms of x = -ln(1 - z)
-fn cli6_unit_circle(x: Complex<f64>) -> Complex<f64> {
+pub(crate) fn cli6_unit_circle(x: Complex<f64>) -> Complex<f64> {
     let bf  = [
         1.0                   , -31.0/64.0             ,
         1.5241340877914952e-01, -3.4365555877057613e-02,
@@ -35,6 +35,7 @@
 
 
 mod cln;
+mod quad;
 mod li0;
 mod li1;
 mod li2;
@@ -43,6 +44,27 @@ mod li4;
 mod li5;
 mod li6;
 mod li;
+mod complement;
+mod deriv;
+mod order_deriv;
+mod const_order;
+mod inv;
+mod all;
+mod orbit;
+mod eval;
+pub mod batch;
+pub mod bose_einstein;
+pub mod debye;
+pub mod expansions;
+pub mod fermi_dirac;
+pub mod integrals;
+pub mod numbers;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod planck;
+pub mod quantum;
+pub mod rational;
+pub mod simd;
 
 pub use self::li0::Li0;
 pub use self::li1::Li1;
@@ -51,4 +73,12 @@ pub use self::li3::Li3;
 pub use self::li4::Li4;
 pub use self::li5::Li5;
 pub use self::li6::Li6;
-pub use self::li::Li;
+pub use self::li::{Li, LiExp};
+pub use self::complement::LiComplement;
+pub use self::deriv::LiDeriv;
+pub use self::order_deriv::LiOrderDeriv;
+pub use self::const_order::LiConst;
+pub use self::inv::LiInv;
+pub use self::all::LiAll;
+pub use self::orbit::Li2Orbit;
+pub use self::eval::{Polylog, PolylogEval};
@@ -0,0 +1,351 @@
+//! Bernoulli, Euler, Stirling and Eulerian numbers.
+//!
+//! The numbers are provided as exact values of type
+//! `num::BigRational` (Bernoulli numbers) or `num::BigInt` (Euler,
+//! Stirling and Eulerian numbers, which are integers), and as values
+//! of type `f64`.  The `f64` variants return `±inf` (with the correct
+//! sign) if the number is too large to be represented.
+//!
+//! # Example:
+//! ```
+//! use num::{BigInt, BigRational};
+//! use polylog::numbers::{bernoulli, bernoulli_f64, euler, stirling2};
+//!
+//! assert_eq!(bernoulli(2), BigRational::new(BigInt::from(1), BigInt::from(6)));
+//! assert_eq!(euler(4), BigInt::from(5));
+//! assert_eq!(stirling2(4, 2), BigInt::from(7));
+//! assert!(bernoulli_f64(1000).is_infinite());
+//! ```
+
+use num::{BigInt, BigRational, One, ToPrimitive, Zero};
+
+/// Returns the n-th Bernoulli number B_n as exact rational number.
+///
+/// The convention B_1 = -1/2 is used.
+///
+/// The even Bernoulli numbers are calculated from the tangent numbers
+/// T_k using the algorithm of Brent and Harvey [[arXiv:1108.0286]]:
+///
+/// B_(2k) = (-1)^(k-1) 2k T_k/(2^(2k) (2^(2k) - 1))
+///
+/// [arXiv:1108.0286]: https://arxiv.org/abs/1108.0286
+pub fn bernoulli(n: u32) -> BigRational {
+    if n == 0 {
+        BigRational::one()
+    } else if n == 1 {
+        BigRational::new(BigInt::from(-1), BigInt::from(2))
+    } else if !is_even(n) {
+        BigRational::zero()
+    } else {
+        let k = n/2;
+        let t = tangent_numbers(k as usize).pop().unwrap();
+        let p = BigInt::one() << (2*k as usize); // 2^(2k)
+        let num = if is_even(k) { -t*BigInt::from(n) } else { t*BigInt::from(n) };
+        BigRational::new(num, &p*(&p - 1))
+    }
+}
+
+/// Returns the n-th Bernoulli number B_n as `f64`.
+///
+/// The convention B_1 = -1/2 is used.  For n > 258 the returned
+/// value is `±inf`.
+///
+/// # Example:
+/// ```
+/// use polylog::numbers::bernoulli_f64;
+///
+/// assert!(bernoulli_f64(2) == 1.0/6.0);
+/// assert!(bernoulli_f64(3) == 0.0);
+/// assert!(bernoulli_f64(260) == f64::NEG_INFINITY);
+/// ```
+pub fn bernoulli_f64(n: u32) -> f64 {
+    if n == 1 {
+        -0.5
+    } else if !is_even(n) {
+        0.0
+    } else if ((n/2) as usize) < BERNOULLI_EVEN.len() {
+        BERNOULLI_EVEN[(n/2) as usize]
+    } else if is_even(n/2) {
+        f64::NEG_INFINITY
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Returns the n-th Euler number E_n as exact integer.
+///
+/// The Euler numbers are defined by 1/cosh(t) = sum(n=0:Inf, E_n t^n/n!).
+/// They are calculated from the secant numbers using the algorithm
+/// of Brent and Harvey [[arXiv:1108.0286]].
+///
+/// [arXiv:1108.0286]: https://arxiv.org/abs/1108.0286
+pub fn euler(n: u32) -> BigInt {
+    if !is_even(n) {
+        BigInt::zero()
+    } else {
+        let k = n/2;
+        let s = secant_numbers(k as usize).pop().unwrap();
+        if is_even(k) { s } else { -s }
+    }
+}
+
+/// Returns the n-th Euler number E_n as `f64`.
+///
+/// For n > 186 the returned value is `±inf`.
+///
+/// # Example:
+/// ```
+/// use polylog::numbers::euler_f64;
+///
+/// assert!(euler_f64(6) == -61.0);
+/// assert!(euler_f64(7) == 0.0);
+/// assert!(euler_f64(188) == f64::INFINITY);
+/// ```
+pub fn euler_f64(n: u32) -> f64 {
+    if !is_even(n) {
+        0.0
+    } else if ((n/2) as usize) < EULER_EVEN.len() {
+        EULER_EVEN[(n/2) as usize]
+    } else if is_even(n/2) {
+        f64::INFINITY
+    } else {
+        f64::NEG_INFINITY
+    }
+}
+
+/// Returns the signed Stirling number of the first kind s(n,k) as
+/// exact integer.
+///
+/// The numbers are defined by x(x-1)...(x-n+1) = sum(k=0:n, s(n,k) x^k).
+pub fn stirling1(n: u32, k: u32) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+
+    // row[j] = s(m,j) for the current m
+    let mut row = vec![BigInt::zero(); (k + 1) as usize];
+    row[0] = BigInt::one();
+
+    for m in 1..=n {
+        for j in (1..=(k.min(m) as usize)).rev() {
+            row[j] = &row[j - 1] - BigInt::from(m - 1)*&row[j];
+        }
+        row[0] = BigInt::zero();
+    }
+
+    row.pop().unwrap()
+}
+
+/// Returns the signed Stirling number of the first kind s(n,k) as
+/// `f64`, or `±inf` if the number is too large to be represented.
+pub fn stirling1_f64(n: u32, k: u32) -> f64 {
+    int_to_f64(stirling1(n, k))
+}
+
+/// Returns the Stirling number of the second kind S(n,k) as exact
+/// integer.
+///
+/// S(n,k) is the number of partitions of a set with n elements into
+/// k non-empty subsets.
+pub fn stirling2(n: u32, k: u32) -> BigInt {
+    if k > n {
+        return BigInt::zero();
+    }
+
+    // row[j] = S(m,j) for the current m
+    let mut row = vec![BigInt::zero(); (k + 1) as usize];
+    row[0] = BigInt::one();
+
+    for m in 1..=n {
+        for j in (1..=(k.min(m) as usize)).rev() {
+            row[j] = &row[j - 1] + BigInt::from(j)*&row[j];
+        }
+        row[0] = BigInt::zero();
+    }
+
+    row.pop().unwrap()
+}
+
+/// Returns the Stirling number of the second kind S(n,k) as `f64`,
+/// or `inf` if the number is too large to be represented.
+pub fn stirling2_f64(n: u32, k: u32) -> f64 {
+    int_to_f64(stirling2(n, k))
+}
+
+/// Returns the Eulerian number A(n,k) as exact integer.
+///
+/// A(n,k) is the number of permutations of 1..n with exactly k
+/// ascents.  The Eulerian numbers are the numerator coefficients of
+/// the polylogarithm of negative order,
+///
+/// Li(-n,z) = sum(k=0:(n-1), A(n,k) z^(k+1))/(1-z)^(n+1),   n > 0
+pub fn eulerian(n: u32, k: u32) -> BigInt {
+    if n == 0 {
+        return if k == 0 { BigInt::one() } else { BigInt::zero() };
+    }
+    if k >= n {
+        return BigInt::zero();
+    }
+    eulerian_row(n).swap_remove(k as usize)
+}
+
+/// Returns the Eulerian number A(n,k) as `f64`, or `inf` if the
+/// number is too large to be represented.
+pub fn eulerian_f64(n: u32, k: u32) -> f64 {
+    int_to_f64(eulerian(n, k))
+}
+
+/// returns [A(n,0), ..., A(n,n-1)] for n > 0 and [A(0,0)] for n = 0
+pub(crate) fn eulerian_row(n: u32) -> Vec<BigInt> {
+    let mut row = vec![BigInt::one()];
+
+    for m in 2..=(n as usize) {
+        let mut next = vec![BigInt::zero(); m];
+        for (k, a) in row.iter().enumerate() {
+            // A(m,k) = (k+1) A(m-1,k) + (m-k) A(m-1,k-1)
+            next[k] += BigInt::from(k + 1)*a;
+            next[k + 1] += BigInt::from(m - k - 1)*a;
+        }
+        row = next;
+    }
+
+    row
+}
+
+/// returns tangent numbers [T_1, ..., T_n] for n > 0
+fn tangent_numbers(n: usize) -> Vec<BigInt> {
+    let mut t = vec![BigInt::one(); n];
+
+    for k in 1..n {
+        t[k] = BigInt::from(k)*&t[k - 1];
+    }
+    for k in 1..n {
+        for j in k..n {
+            t[j] = BigInt::from(j - k)*&t[j - 1] + BigInt::from(j - k + 2)*&t[j];
+        }
+    }
+
+    t
+}
+
+/// returns secant numbers [S_0, ..., S_n] for n >= 0
+fn secant_numbers(n: usize) -> Vec<BigInt> {
+    let mut s = vec![BigInt::one(); n + 1];
+
+    for k in 1..=n {
+        s[k] = BigInt::from(k)*&s[k - 1];
+    }
+    for k in 1..=n {
+        for j in (k + 1)..=n {
+            s[j] = BigInt::from(j - k)*&s[j - 1] + BigInt::from(j - k + 1)*&s[j];
+        }
+    }
+
+    s
+}
+
+/// returns true if x is even, false otherwise
+fn is_even(x: u32) -> bool {
+    x & 1 == 0
+}
+
+/// converts an integer to the closest `f64`, or `±inf` on overflow
+fn int_to_f64(x: BigInt) -> f64 {
+    BigRational::from_integer(x).to_f64().unwrap()
+}
+
+// Table[BernoulliB[2n], {n,0,129}]
+const BERNOULLI_EVEN: [f64; 130] = [
+                        1.0,     0.16666666666666666,    -0.03333333333333333,
+       0.023809523809523808,    -0.03333333333333333,     0.07575757575757576,
+        -0.2531135531135531,      1.1666666666666667,      -7.092156862745098,
+         54.971177944862156,      -529.1242424242424,       6192.123188405797,
+         -86580.25311355312,      1425517.1666666667,     -27298231.067816094,
+          601580873.9006424,     -15116315767.092157,       429614643061.1667,
+        -13711655205088.332,       488332318973593.2,  -1.9296579341940068e16,
+       8.416930475736826e17,  -4.0338071854059454e19,   2.1150748638081993e21,
+     -1.2086626522296526e23,    7.500866746076964e24,   -5.038778101481069e26,
+      3.6528776484818122e28,   -2.849876930245088e30,   2.3865427499683627e32,
+     -2.1399949257225335e34,   2.0500975723478097e36,   -2.093800591134638e38,
+      2.2752696488463515e40,  -2.6257710286239577e42,    3.212508210271803e44,
+      -4.159827816679471e46,    5.692069548203528e48,   -8.218362941978458e50,
+      1.2502904327166994e53,   -2.001558323324837e55,   3.3674982915364376e57,
+      -5.947097050313545e59,   1.1011910323627977e62,  -2.1355259545253502e64,
+      4.3328896986641194e66,   -9.188552824166933e68,   2.0346896776329074e71,
+      -4.700383395803573e73,    1.131804344548425e76,  -2.8382249570693707e78,
+       7.406424897967885e80,  -2.0096454802756605e83,    5.665717005080594e85,
+     -1.6584511154136216e88,    5.036885995049238e90,  -1.5861468237658186e93,
+      5.1756743617545625e95,  -1.7488921840217116e98,   6.116051999495218e100,
+    -2.2122776912707833e103,   8.272277679877097e105,  -3.195892511141571e108,
+     1.2750082223387793e111,  -5.250092308677413e113,  2.2301817894241627e116,
+      -9.76845219309552e118,   4.409836197845295e121,  -2.050857088646409e124,
+      9.821443327979128e126,  -4.841260079820888e129,  2.4553088801480982e132,
+    -1.2806926804084748e135,   6.867616710466858e137, -3.7846468581969106e140,
+      2.142610125066529e143, -1.2456727137183695e146,   7.434578755100016e148,
+    -4.5535795304641704e151,   2.861211281685887e154,  -1.843772355203387e157,
+     1.2181154536221047e160,  -8.248218718531412e162,   5.722587793783294e165,
+    -4.0668530525059105e168,  2.9596092064642052e171, -2.2049522565189457e174,
+       1.68125970728896e177, -1.3116736213556958e180,  1.0467894009478039e183,
+     -8.543289357883371e185,   7.128782132248655e188,   -6.08029314555359e191,
+      5.299677642484992e194,  -4.719425916874586e197,   4.292841379140298e200,
+    -3.9876744968232205e203,   3.781978041935888e206,  -3.661423368368119e209,
+      3.617609027237286e212,  -3.647077264519136e215,   3.750875543645441e218,
+     -3.934586729643903e221,   4.208821114819008e224,  -4.590229622061792e227,
+      5.103172577262957e230,  -5.782276230365695e233,   6.676248216783588e236,
+     -7.853530764445042e239,   9.410689406705872e242, -1.1484933873465185e246,
+     1.4272958742848785e249,  -1.805955958690931e252,  2.3261535307660807e255,
+    -3.0495751715499594e258,   4.068580607643398e261,  -5.523103132197436e264,
+     7.6277279396434395e267, -1.0715571119697886e271,  1.5310200895969188e274,
+    -2.2244891682179836e277,   3.286267919069014e280,  -4.935592895596035e283,
+      7.534957120083251e286, -1.1691485154584178e290,   1.843526146783894e293,
+     -2.953682617296808e296,   4.807932127750157e299,  -7.950212504588525e302,
+     1.3352784187354634e306,
+];
+
+// Table[EulerE[2n], {n,0,93}]
+const EULER_EVEN: [f64; 94] = [
+                        1.0,                    -1.0,                     5.0,
+                      -61.0,                  1385.0,                -50521.0,
+                  2702765.0,            -199360981.0,           19391512145.0,
+           -2404879675441.0,       370371188237525.0,    -6.93488743931379e16,
+      1.5514534163557087e19,   -4.087072509293124e21,     1.25225964140363e24,
+      -4.415438932490231e26,    1.775193915795393e29,    -8.07232992358879e31,
+      4.1222060339517704e34,  -2.3489580527043107e37,    1.485115071811498e40,
+     -1.0364622733519612e43,    7.947579422597592e45,   -6.667537516685545e48,
+       6.096278645568542e51,   -6.053285248188622e54,    6.506162486684609e57,
+      -7.546659939008739e60,    9.420321896420241e63,  -1.2622019251806219e67,
+       1.810891149657923e70,  -2.7757101702071582e73,    4.535810333001789e76,
+      -7.886284206661789e79,    1.456184438013963e83,   -2.850517832236977e86,
+       5.905747207775443e89,   -1.292973664187864e93,    2.986928183284577e96,
+      -7.270601714016864e99,  1.8622915758412697e103,   -5.01310494081098e106,
+     1.4165255759785626e110, -4.1966431640402446e113,  1.3021595905240464e117,
+     -4.227240686139909e120,  1.4343212791976582e124,  -5.081799072458043e127,
+       1.87833293645293e131,  -7.236534381033858e134,  2.9035283466610976e138,
+    -1.2122937378929218e142,    5.26306424961699e145,  -2.374073071936766e149,
+     1.1118900942482822e153,  -5.403078659795293e156,   2.722341085572227e160,
+     -1.421301054800967e164,   7.684261820646903e167, -4.2996219254397495e171,
+      2.488391574782987e175, -1.4887582089062041e179,   9.202614118852094e182,
+     -5.874244457292436e186,   3.870133554175927e190,  -2.630384646272822e194,
+     1.8434218619068164e198, -1.3315007608319975e202,     9.9077340794641e205,
+     -7.591616153760865e209,   5.987386904215955e213,   -4.85853153680527e217,
+      4.054747377507915e221,  -3.478923713390906e225,   3.067497388251085e229,
+     -2.778574047804574e233,  2.5846560390271182e237, -2.4681704804636407e241,
+     2.4187539760367133e245,  -2.431692647091073e249,  2.5071830005737145e253,
+    -2.6502520005258137e257,  2.8713019731666796e261,  -3.187360216235411e265,
+     3.6242416450584564e269,  -4.220005513130261e273,   5.030345578531501e277,
+     -6.136961784942134e281,   7.660628138463374e285,  -9.781780112839675e289,
+     1.2773316636719806e294, -1.7053514185447204e298,    2.32725003482003e302,
+     -3.245547458389247e306,
+];
+
+#[test]
+fn test_tangent_numbers() {
+    let t: Vec<BigInt> = [1, 2, 16, 272, 7936].iter().map(|&x| BigInt::from(x)).collect();
+    assert_eq!(tangent_numbers(5), t);
+}
+
+#[test]
+fn test_secant_numbers() {
+    let s: Vec<BigInt> = [1, 1, 5, 61, 1385].iter().map(|&x| BigInt::from(x)).collect();
+    assert_eq!(secant_numbers(4), s);
+}
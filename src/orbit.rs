@@ -0,0 +1,108 @@
+use num::complex::Complex;
+use crate::cln::CLn;
+use crate::Li2;
+use crate::li2::cli2_approx_pair;
+
+/// Provides the dilogarithms `li2_orbit()` of a number z of type `T`
+/// at the six arguments of the anharmonic group, i.e. `z.li2_orbit()`
+/// returns the array
+/// [Li2(z), Li2(1-z), Li2(1/z), Li2(1/(1-z)), Li2(z/(z-1)), Li2((z-1)/z)].
+///
+/// Every element agrees with `li2()` of the corresponding argument,
+/// including the branch conventions on the real axis.
+pub trait Li2Orbit<T> {
+    fn li2_orbit(&self) -> [T; 6];
+}
+
+impl Li2Orbit<f64> for f64 {
+    /// Returns the real dilogarithms [Re[Li2(x)], Re[Li2(1-x)],
+    /// Re[Li2(1/x)], Re[Li2(1/(1-x))], Re[Li2(x/(x-1))],
+    /// Re[Li2((x-1)/x)]] of a real number x of type `f64`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::{Li2, Li2Orbit};
+    ///
+    /// let li = 3.0_f64.li2_orbit();
+    /// assert!(li[0] == 3.0_f64.li2() && li[3] == (-0.5_f64).li2());
+    /// ```
+    fn li2_orbit(&self) -> [f64; 6] {
+        let x = *self;
+        [x, 1.0 - x, 1.0/x, 1.0/(1.0 - x), x/(x - 1.0), (x - 1.0)/x].map(|w| w.li2())
+    }
+}
+
+impl Li2Orbit<Complex<f64>> for Complex<f64> {
+    /// Returns the complex dilogarithms [Li2(z), Li2(1-z), Li2(1/z),
+    /// Li2(1/(1-z)), Li2(z/(z-1)), Li2((z-1)/z)] of a complex number z
+    /// of type `Complex<f64>`.
+    ///
+    /// For non-real z the series expansion is evaluated only once for
+    /// the pair of arguments in the region Re(v) <= 1/2 and |v| <= 1,
+    /// the other four values follow from the reflection and the
+    /// inversion formulas.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li2, Li2Orbit};
+    ///
+    /// let z = Complex::new(1.0_f64, 1.0_f64);
+    /// let li = z.li2_orbit();
+    /// assert!((li[2] - (1.0/z).li2()).norm() < 4.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li2_orbit(&self) -> [Complex<f64>; 6] {
+        let z = *self;
+
+        if z.im == 0.0 || !z.is_finite() {
+            let one = Complex::new(1.0, 0.0);
+            return [z, one - z, one/z, one/(one - z), z/(z - one), (z - one)/z].map(|w| w.li2());
+        }
+
+        let z2 = std::f64::consts::PI*std::f64::consts::PI/6.0;
+        let rz = z.re;
+        let nz = z.norm_sqr();
+
+        // v lies in the region Re(v) <= 1/2 and |v| <= 1
+        let (case, v) = if rz <= 0.5 && nz <= 1.0 {
+            (0, z)
+        } else if rz <= 0.5 || nz > 2.0*rz {
+            (1, 1.0/z)
+        } else {
+            (2, 1.0 - z)
+        };
+
+        let vp = v/(v - 1.0);
+        let x = if v.norm_sqr() < f64::EPSILON {
+            v*(1.0 + 0.5*v)
+        } else {
+            -(1.0 - v).cln()
+        };
+        let (lv, lmv) = ln_pm(v);
+        let (lvp, lmvp) = ln_pm(vp);
+        let (bp, bm) = cli2_approx_pair(x);
+
+        let a = bp;                             // Li2(v)
+        let b = bm;                             // Li2(v/(v-1))
+        let c = z2 - bp + x*lv;                 // Li2(1-v)
+        let d = z2 - bm - x*lvp;                // Li2(1/(1-v))
+        let e = -z2 - bp - 0.5*lmv*lmv;         // Li2(1/v)
+        let f = -z2 - bm - 0.5*lmvp*lmvp;       // Li2((v-1)/v)
+
+        match case {
+            0 => [a, c, e, d, b, f], // v = z
+            1 => [e, f, a, b, d, c], // v = 1/z
+            _ => [c, a, d, e, f, b], // v = 1-z
+        }
+    }
+}
+
+/// returns (ln(z), ln(-z)) for non-real z
+fn ln_pm(z: Complex<f64>) -> (Complex<f64>, Complex<f64>) {
+    let pi  = std::f64::consts::PI;
+    let lnz = z.norm().ln();
+    let arg = z.arg();
+    let marg = if arg > 0.0 { arg - pi } else { arg + pi };
+
+    (Complex::new(lnz, arg), Complex::new(lnz, marg))
+}
@@ -0,0 +1,578 @@
+use num::complex::Complex;
+use crate::cln::{CLn, expm1};
+use crate::li::fac::{fac, stirling_ratio};
+use crate::li::zeta::zeta;
+use crate::numbers::{bernoulli_f64, stirling2_f64};
+use crate::quad::{integrate_pole, pole_height};
+
+/// Provides the derivatives `li_order_derivs()` of the polylogarithm
+/// Li(s,z) of a number z of type `T` with respect to the order s at
+/// integer s = n.  `z.li_order_derivs(n, m)` returns the vector
+///
+/// [Li(n,z), d/ds Li(s,z), ..., d^m/ds^m Li(s,z)]   at s = n.
+///
+/// For |z| <= 3/4 the series
+///
+/// d^i/ds^i Li(s,z) = sum(k=1:Inf, (-ln(k))^i z^k/k^s)
+///
+/// is used, where for n <= 0 the series is restricted to
+/// 4 pi^2 |z|^2 < |ln(z)|^2 as in `Li::li`.  Otherwise the expansion
+/// around z = 1 in mu = ln(z),
+///
+/// Li(s,e^mu) = Gamma(1-s) (-mu)^(s-1) + sum(j=0:Inf, zeta(s-j) mu^j/j!)
+///
+/// is differentiated with respect to s, which requires the derivatives
+/// zeta'(n), zeta''(n), ... at integer n.  For n >= 1 the pole of
+/// Gamma(1-s) at s = n cancels against the pole of zeta(s-n+1), whose
+/// regular part is given by the Stieltjes constants,
+///
+/// zeta(1+e) = 1/e + sum(k=0:Inf, (-1)^k gamma_k e^k/k!)
+///
+/// The expansion converges for |ln(z)| < 2 pi.  It is used for
+/// |ln(z)| < 4.  Otherwise the integral representation
+///
+/// Li(n+e,z) = 1/Gamma(s0+e) int(t=0:Inf, t^(s0-1+e) Li(n-s0,z e^(-t)))
+///
+/// with s0 = max(n,2) is differentiated with respect to e, where
+/// Li(n-s0,w) is a rational function of w.  For |arg(z)| > 2 pi/3
+/// the duplication formula
+///
+/// Li(s,z) = 2^(1-s) Li(s,z^2) - Li(s,-z)
+///
+/// is applied for n >= 1 to avoid cancellations in the expansion.
+/// At the pole of zeta(s) at z = 1 and n = 1 infinity is returned.
+pub trait LiOrderDeriv<T> {
+    fn li_order_derivs(&self, n: i32, m: usize) -> Vec<T>;
+}
+
+impl LiOrderDeriv<f64> for f64 {
+    /// Returns the real n-th order polylogarithm Re[Li(n,x)] and its
+    /// derivatives with respect to the order up to degree m for a real
+    /// number x of type `f64`.
+    ///
+    /// # Example:
+    /// ```
+    /// use polylog::LiOrderDeriv;
+    ///
+    /// // d/ds Li(s,1) = zeta'(s)
+    /// let d = 1.0_f64.li_order_derivs(2, 1);
+    /// assert!((d[0] - 1.6449340668482264_f64).abs() < 2.0_f64*std::f64::EPSILON);
+    /// assert!((d[1] + 0.93754825431584375_f64).abs() < 4.0_f64*std::f64::EPSILON);
+    /// ```
+    fn li_order_derivs(&self, n: i32, m: usize) -> Vec<f64> {
+        Complex::new(*self, 0.0).li_order_derivs(n, m).iter().map(|d| d.re).collect()
+    }
+}
+
+impl LiOrderDeriv<Complex<f64>> for Complex<f64> {
+    /// Returns the complex n-th order polylogarithm Li(n,z) and its
+    /// derivatives with respect to the order up to degree m for a
+    /// complex number z of type `Complex<f64>`.
+    ///
+    /// # Example:
+    /// ```
+    /// use num::complex::Complex;
+    /// use polylog::{Li, LiOrderDeriv};
+    ///
+    /// let z = Complex::new(1.0_f64, 1.0_f64);
+    /// let d = z.li_order_derivs(3, 2);
+    /// assert!((d[0] - z.li(3)).norm() < 2.0_f64*std::f64::EPSILON);
+    /// assert!((d[1] - Complex::new(0.14553331225724960_f64, -0.16573118317804978_f64)).norm() < 1e-14_f64);
+    /// ```
+    fn li_order_derivs(&self, n: i32, m: usize) -> Vec<Complex<f64>> {
+        let z = *self;
+
+        if z.is_nan() {
+            return vec![Complex::new(f64::NAN, f64::NAN); m + 1];
+        }
+
+        let pi = std::f64::consts::PI;
+        let mu = z.cln();
+
+        if is_series(n, z, mu) {
+            order_derivs_series(n, m, z)
+        } else {
+            let sgn = if mu.im > 0.0 { 1.0 } else { -1.0 };
+            let mu_neg = Complex::new(mu.re, mu.im - sgn*pi); // ln(-z)
+            let mu_sqr = 2.0*mu_neg;                          // ln(z^2)
+
+            if n >= 1 && mu.im.abs() > 2.0/3.0*pi && mu_sqr.norm() < 4.0 {
+                order_derivs_dup(n, m, mu_neg, mu_sqr)
+            } else if mu.norm() < 4.0 {
+                order_derivs_unity(n, m, mu)
+            } else if z.is_infinite() {
+                vec![Complex::new(f64::NAN, f64::NAN); m + 1]
+            } else {
+                order_derivs_integral(n, m, z, mu)
+            }
+        }
+    }
+}
+
+/// returns true if the series is used for z = e^mu; for n <= 0 the
+/// criterion of arXiv:2010.09860 is used, because the coefficients of
+/// the series grow
+fn is_series(n: i32, z: Complex<f64>, mu: Complex<f64>) -> bool {
+    if n >= 1 {
+        z.norm_sqr() <= 0.75*0.75
+    } else {
+        4.0*std::f64::consts::PI*std::f64::consts::PI*z.norm_sqr() < mu.norm_sqr()
+    }
+}
+
+/// Euler-Mascheroni constant gamma_0
+const EULER_GAMMA: f64 = 0.5772156649015329;
+
+/// returns d^i/ds^i Li(s,z) at s = n for i = 0,...,m from the series
+/// sum(k=1:Inf, (-ln(k))^i z^k/k^n) for |z| < 1
+fn order_derivs_series(n: i32, m: usize, z: Complex<f64>) -> Vec<Complex<f64>> {
+    let mut sum = vec![Complex::new(0.0, 0.0); m + 1];
+    let mut zk = Complex::new(1.0, 0.0); // collects z^k
+
+    for k in 1..i32::MAX {
+        zk *= z;
+        let l = -(k as f64).ln();
+        let mut term = zk/(k as f64).powi(n);
+        let mut changed = false;
+
+        for s in sum.iter_mut() {
+            let old_s = *s;
+            *s += term;
+            changed = changed || *s != old_s;
+            term *= l;
+        }
+
+        if k > 1 && !changed { break; }
+    }
+
+    sum
+}
+
+/// returns d^i/ds^i Li(s,z) at s = n for i = 0,...,m from the
+/// duplication formula, given ln(-z) and ln(z^2)
+fn order_derivs_dup(n: i32, m: usize, mu_neg: Complex<f64>, mu_sqr: Complex<f64>) -> Vec<Complex<f64>> {
+    let l2 = -std::f64::consts::LN_2;
+
+    if n == 1 && mu_neg.re == 0.0 && mu_neg.im == 0.0 {
+        // Li(1+e,-1) = (2^(-e) - 1) zeta(1+e), where the pole cancels
+        let mut p = 1.0; // collects (-ln(2))^i/i!
+        let zr = zeta_taylor(1, m);
+        let mut e2 = vec![0.0; m + 2];
+        for (i, c) in e2.iter_mut().enumerate().skip(1) {
+            p *= l2/(i as f64);
+            *c = p;
+        }
+        let r = series_mul(&e2, &zr);
+        return to_derivs((0..=m).map(|i| Complex::new(e2[i + 1] + r[i], 0.0)).collect());
+    }
+
+    let dn = order_derivs_unity(n, m, mu_neg);
+    let ds = order_derivs_unity(n, m, mu_sqr);
+
+    // d^k/ds^k 2^(1-s) = (-ln(2))^k 2^(1-s)
+    let p2 = 2.0_f64.powi(1 - n);
+
+    (0..=m).map(|i| {
+        let mut binom = 1.0; // collects binomial(i,k)
+        let mut lk = p2;     // collects (-ln(2))^k 2^(1-n)
+        let mut sum = Complex::new(0.0, 0.0);
+        for k in 0..=i {
+            sum += binom*lk*ds[i - k];
+            binom *= ((i - k) as f64)/((k + 1) as f64);
+            lk *= l2;
+        }
+        sum - dn[i]
+    }).collect()
+}
+
+/// returns d^i/ds^i Li(s,e^mu) at s = n for i = 0,...,m from the
+/// expansion around mu = 0
+fn order_derivs_unity(n: i32, m: usize, mu: Complex<f64>) -> Vec<Complex<f64>> {
+    if mu.re == 0.0 && mu.im == 0.0 {
+        // Li(s,1) = zeta(s)
+        return if n == 1 {
+            vec![Complex::new(f64::INFINITY, 0.0); m + 1]
+        } else {
+            to_derivs(zeta_taylor(n, m).iter().map(|c| Complex::new(*c, 0.0)).collect())
+        };
+    }
+
+    // coefficients of e^i in Li(n+e,e^mu)
+    let mut coeffs = gamma_part(n, m, mu);
+    let mut p = Complex::new(1.0, 0.0); // collects mu^j/j!
+    let mut unchanged = 0;
+
+    for j in 0..i32::MAX {
+        if j > 0 {
+            p *= mu/(j as f64);
+        }
+        if n >= 1 && j == n - 1 {
+            continue;
+        }
+
+        let terms: Vec<Complex<f64>> = zeta_taylor(n - j, m).iter().map(|z| z*p).collect();
+        if terms.iter().any(|t| !t.is_finite()) { break; }
+
+        let mut changed = false;
+
+        for (c, t) in coeffs.iter_mut().zip(terms.iter()) {
+            let old_c = *c;
+            *c += t;
+            changed = changed || *c != old_c;
+        }
+
+        // terms may vanish at the trivial zeros of zeta(n-j)
+        unchanged = if changed { 0 } else { unchanged + 1 };
+        if j >= n && unchanged >= 2 { break; }
+    }
+
+    to_derivs(coeffs)
+}
+
+/// returns d^i/ds^i Li(s,z) at s = n for i = 0,...,m from the
+/// integral representation, see `LiOrderDeriv`, given mu = ln(z)
+///
+/// The rational function is evaluated as
+///
+/// Li(-p,w) = sum(k=0:p, k! S(p+1,k+1) (w/(1-w))^(k+1))
+///
+/// with the Stirling numbers of the second kind S(n,k), where for
+/// |w| > 1 and p >= 1 the relation Li(-p,w) = (-1)^(p+1) Li(-p,1/w)
+/// is used to avoid cancellations.  The integrand is divided by
+/// e^(-t) t0^(s0-1) e^(-(s0-1)), where t0 = s0 - 1 is the position of
+/// the maximum of t^(s0-1) e^(-t).
+fn order_derivs_integral(n: i32, m: usize, z: Complex<f64>, mu: Complex<f64>) -> Vec<Complex<f64>> {
+    let s0 = n.max(2);
+    let p = (s0 - n) as u32;
+    let q = f64::from(s0 - 1);
+    let sigma = q.sqrt();
+    let sgn = if is_even(p) { -1.0 } else { 1.0 }; // (-1)^(p+1)
+    let coeffs: Vec<f64> = (0..=p).map(|k| fac(k as i32)*stirling2_f64(p + 1, k + 1)).collect();
+    let poly = |x: Complex<f64>| coeffs.iter().rev().fold(Complex::new(0.0, 0.0), |sum, c| sum*x + c);
+
+    // t^(s0-1) e^(-t) e^t Li(-p,z e^(-t)), divided by t0^(s0-1) e^(-(s0-1))
+    let f = |t: Complex<f64>| {
+        let v = t/q;
+        let pre = (q*(v.ln() - v + 1.0)).exp();
+        let em = expm1(mu - t); // w - 1
+        if p >= 1 && t.re < mu.re {
+            let v = em.inv();
+            pre*sgn*t.exp()*v*poly(v)
+        } else {
+            let u = -(mu - t).exp()/em;
+            pre*(-z/em)*poly(u)
+        }
+    };
+
+    // the contribution from [0,a] is negligible
+    let a = 1e-12;
+    let c = if mu.re > a { pole_height(q, f64::from(p + 1), mu.re) } else { 0.0 };
+    let tmin = (q + 10.0*sigma).max(mu.re + 1.0);
+
+    // integrals of f(t) ln(t)^j/j!
+    let ints: Vec<Complex<f64>> = (0..=m).map(|j| {
+        let g = |t: Complex<f64>| f(t)*t.ln().powi(j as i32)*inv_fac_f(j);
+        integrate_pole(g, a, mu, c, sigma.min(16.0), tmin)*stirling_ratio(s0 - 1)
+    }).collect();
+
+    // Taylor coefficients of Gamma(s0)/Gamma(s0+e)
+    let mut lng = vec![0.0; m + 1];
+    if m >= 1 {
+        lng[1] = EULER_GAMMA - harmonic_gen(s0 - 1, 1);
+    }
+    for (k, c) in lng.iter_mut().enumerate().skip(2) {
+        let sgn = if k % 2 == 0 { -1.0 } else { 1.0 };
+        *c = sgn*(zeta(k as i32) - harmonic_gen(s0 - 1, k as i32))/(k as f64);
+    }
+    let g = series_exp(&lng);
+
+    to_derivs((0..=m).map(|i| (0..=i).map(|j| g[i - j]*ints[j]).sum()).collect())
+}
+
+/// returns the derivatives i! c_i from the Taylor coefficients c_i
+fn to_derivs(mut coeffs: Vec<Complex<f64>>) -> Vec<Complex<f64>> {
+    let mut fac = 1.0;
+    for (i, c) in coeffs.iter_mut().enumerate() {
+        if i > 0 { fac *= i as f64; }
+        *c *= fac;
+    }
+    coeffs
+}
+
+/// returns the coefficients of e^i, i = 0,...,m, of
+///
+/// Gamma(1-n-e) (-mu)^(n-1+e) + zeta(1+e) mu^(n-1)/(n-1)!   for n >= 1
+///
+/// Gamma(1-n-e) (-mu)^(n-1+e)                             for n <= 0
+///
+/// where the poles at e = 0 cancel for n >= 1.
+fn gamma_part(n: i32, m: usize, mu: Complex<f64>) -> Vec<Complex<f64>> {
+    let l = (-mu).cln(); // ln(-mu)
+
+    // e^(e ln(-mu))
+    let mut el = vec![Complex::new(1.0, 0.0); m + 2];
+    for i in 1..(m + 2) {
+        el[i] = el[i - 1]*l/(i as f64);
+    }
+
+    if n >= 1 {
+        // Gamma(1-n-e) = (-1)^n Gamma(1-e)/(e (1+e) ... (n-1+e)) = (-1)^n G(e)/e
+        // ln(G(e)) = ln(Gamma(1-e)) - sum(i=1:n-1, ln(i+e))
+        let mut lng = vec![0.0; m + 2];
+        lng[0] = -(1..n).map(|i| (i as f64).ln()).sum::<f64>();
+        lng[1] = EULER_GAMMA - harmonic_gen(n - 1, 1);
+        for (k, c) in lng.iter_mut().enumerate().skip(2) {
+            let sgn = if k % 2 == 0 { 1.0 } else { -1.0 };
+            *c = (zeta(k as i32) + sgn*harmonic_gen(n - 1, k as i32))/(k as f64);
+        }
+        let g = series_exp(&lng);
+
+        // H(e) = G(e) e^(e ln(-mu))
+        let h = series_mul_complex(&g, &el);
+
+        // first part: mu^(n-1) [zeta_reg(1+e)/(n-1)! - sum(i, h_(i+1) e^i)]
+        let zr = zeta_taylor(1, m);
+        let mun = mu.powi(n - 1);
+        let g0 = g[0];
+
+        (0..=m).map(|i| mun*(zr[i]*g0 - h[i + 1])).collect()
+    } else {
+        // Gamma(N-e) with N = 1-n >= 1
+        let nn = 1 - n;
+        let mut lng = vec![0.0; m + 1];
+        lng[0] = (2..nn).map(|i| (i as f64).ln()).sum::<f64>();
+        if m >= 1 {
+            lng[1] = -(harmonic_gen(nn - 1, 1) - EULER_GAMMA);
+        }
+        for (k, c) in lng.iter_mut().enumerate().skip(2) {
+            *c = (zeta(k as i32) - harmonic_gen(nn - 1, k as i32))/(k as f64);
+        }
+        // factor out Gamma(N) (-mu)^(n-1) to avoid an overflow
+        let pre = (lng[0] + ((n - 1) as f64)*l).exp();
+        lng[0] = 0.0;
+        let g = series_exp(&lng);
+        let h = series_mul_complex(&g, &el[..(m + 1)]);
+
+        h.iter().map(|c| pre*c).collect()
+    }
+}
+
+/// returns the generalized harmonic number H(n,k) = sum(i=1:n, 1/i^k)
+fn harmonic_gen(n: i32, k: i32) -> f64 {
+    (1..=n).rev().map(|i| (i as f64).powi(-k)).sum()
+}
+
+/// returns the coefficients c_i, i = 0,...,m, of the Taylor expansion
+///
+/// zeta(s+e) = sum(i=0:m, c_i e^i)
+///
+/// for integer s.  For s = 1 the regular part zeta(1+e) - 1/e is
+/// returned, whose coefficients are (-1)^i gamma_i/i! with the
+/// Stieltjes constants gamma_i.
+///
+/// For s >= 1 the Euler-Maclaurin formula is used.  For s <= 0 the
+/// functional equation
+///
+/// zeta(s) = 2^s pi^(s-1) sin(pi s/2) Gamma(1-s) zeta(1-s)
+///
+/// is applied.
+fn zeta_taylor(s: i32, m: usize) -> Vec<f64> {
+    if s >= 1 {
+        zeta_taylor_em(s, m)
+    } else {
+        zeta_taylor_neg(s, m)
+    }
+}
+
+/// returns the Taylor coefficients of zeta(s+e) for integer s >= 1
+/// from the Euler-Maclaurin formula
+///
+/// zeta(s) = sum(k=1:K-1, k^(-s)) + K^(1-s)/(s-1) + K^(-s)/2
+///           + sum(j=1:p, B_2j/(2j)! s (s+1) ... (s+2j-2) K^(1-s-2j))
+fn zeta_taylor_em(s: i32, m: usize) -> Vec<f64> {
+    const K: i32 = 16;
+    const P: i32 = 14;
+    let kf = K as f64;
+    let lk = kf.ln();
+
+    // series of e^(-e ln(k))
+    let exp_ln = |k: f64| -> Vec<f64> {
+        let l = -k.ln();
+        let mut v = vec![1.0; m + 1];
+        for i in 1..=m {
+            v[i] = v[i - 1]*l/(i as f64);
+        }
+        v
+    };
+
+    let mut sum = vec![0.0; m + 1];
+
+    for k in 1..K {
+        let pre = (k as f64).powi(-s);
+        for (c, e) in sum.iter_mut().zip(exp_ln(k as f64).iter()) {
+            *c += pre*e;
+        }
+    }
+
+    let ek = exp_ln(kf); // K^(-e)
+
+    // K^(1-s-e)/(s-1+e)
+    if s == 1 {
+        // (K^(-e) - 1)/e, the pole 1/e is omitted
+        for (i, c) in sum.iter_mut().enumerate() {
+            *c += (-lk).powi(i as i32 + 1)*inv_fac_f(i + 1);
+        }
+    } else {
+        let a = (s - 1) as f64;
+        let inv: Vec<f64> = (0..=m).map(|i| (-1.0_f64).powi(i as i32)/a.powi(i as i32 + 1)).collect();
+        let t = series_mul(&ek, &inv);
+        let pre = kf.powi(1 - s);
+        for (c, x) in sum.iter_mut().zip(t.iter()) {
+            *c += pre*x;
+        }
+    }
+
+    // K^(-s-e)/2
+    let pre = 0.5*kf.powi(-s);
+    for (c, x) in sum.iter_mut().zip(ek.iter()) {
+        *c += pre*x;
+    }
+
+    // Bernoulli terms
+    let mut poch = vec![0.0; m + 1]; // (s+e) (s+1+e) ... (s+2j-2+e)
+    poch[0] = 1.0;
+    let mut fac2j = 1.0; // (2j)!
+
+    for j in 1..=P {
+        let r = 2*j - 2;
+        // multiply by (s+r-1+e) (s+r+e), where the first factor is
+        // omitted for j = 1
+        let factors = if j == 1 { vec![s] } else { vec![s + r - 1, s + r] };
+        for f in factors {
+            let f = f as f64;
+            for i in (0..=m).rev() {
+                poch[i] = poch[i]*f + if i > 0 { poch[i - 1] } else { 0.0 };
+            }
+        }
+        fac2j *= ((2*j - 1)*(2*j)) as f64;
+        let b = bernoulli_f64(2*j as u32)/fac2j*kf.powi(1 - s - 2*j);
+        let t = series_mul(&poch, &ek);
+        for (c, x) in sum.iter_mut().zip(t.iter()) {
+            *c += b*x;
+        }
+    }
+
+    sum
+}
+
+/// returns the Taylor coefficients of zeta(s+e) for integer s <= 0
+/// from the functional equation
+fn zeta_taylor_neg(s: i32, m: usize) -> Vec<f64> {
+    let pi = std::f64::consts::PI;
+    let nn = 1 - s; // N = 1 - s >= 1
+
+    // ln(2^(s+e) pi^(s+e-1) Gamma(N-e)), where Gamma(N-e) is expanded
+    // with the polygamma functions at integer N and
+    // 2^s pi^(s-1) Gamma(N) = prod(i=1:N-1, i/(2 pi))/pi
+    let pre = (1..nn).fold(1.0/pi, |p, i| p*(i as f64)/(2.0*pi));
+    let mut lnf = vec![0.0; m + 1];
+    if m >= 1 {
+        lnf[1] = (2.0*pi).ln() - (harmonic_gen(nn - 1, 1) - EULER_GAMMA);
+    }
+    for (k, c) in lnf.iter_mut().enumerate().skip(2) {
+        *c = (zeta(k as i32) - harmonic_gen(nn - 1, k as i32))/(k as f64);
+    }
+    let f: Vec<f64> = series_exp(&lnf).iter().map(|c| pre*c).collect();
+
+    // sin(pi (s+e)/2) = sin(pi s/2) cos(pi e/2) + cos(pi s/2) sin(pi e/2)
+    let (ss, cs) = match s.rem_euclid(4) {
+        0 => (0.0, 1.0),
+        1 => (1.0, 0.0),
+        2 => (0.0, -1.0),
+        _ => (-1.0, 0.0),
+    };
+    let mut sin = vec![0.0; m + 2];
+    let mut p = 1.0; // collects (pi/2)^i/i!
+    for (i, c) in sin.iter_mut().enumerate() {
+        if i > 0 { p *= 0.5*pi/(i as f64); }
+        *c = match i % 4 {
+            0 => ss*p,
+            1 => cs*p,
+            2 => -ss*p,
+            _ => -cs*p,
+        };
+    }
+
+    // zeta(N-e), where for N = 1 the pole -1/e is cancelled against
+    // the zero of sin(pi e/2)
+    let zt: Vec<f64> = zeta_taylor_em(nn, m).iter().enumerate()
+        .map(|(i, c)| if i % 2 == 0 { *c } else { -c }).collect();
+
+    if s == 0 {
+        // sin(pi e/2)/e and e zeta(1-e) = -1 + e zeta_reg(1-e)
+        let zt: Vec<f64> = std::iter::once(-1.0).chain(zt.into_iter().take(m)).collect();
+        series_mul(&series_mul(&f, &sin[1..]), &zt)
+    } else {
+        series_mul(&series_mul(&f, &sin), &zt)
+    }
+}
+
+/// returns 1/n!
+fn inv_fac_f(n: usize) -> f64 {
+    (1..=n).fold(1.0, |p, i| p/(i as f64))
+}
+
+/// returns the product of two truncated power series
+fn series_mul(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let len = a.len().min(b.len());
+    (0..len).map(|i| (0..=i).map(|j| a[j]*b[i - j]).sum()).collect()
+}
+
+/// returns the product of a real and a complex truncated power series
+fn series_mul_complex(a: &[f64], b: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let len = a.len().min(b.len());
+    (0..len).map(|i| (0..=i).map(|j| a[j]*b[i - j]).sum()).collect()
+}
+
+/// returns the truncated power series of exp(a(e))
+fn series_exp(a: &[f64]) -> Vec<f64> {
+    // b' = a' b
+    let mut b = vec![0.0; a.len()];
+    b[0] = a[0].exp();
+    for i in 1..a.len() {
+        b[i] = (1..=i).map(|k| (k as f64)*a[k]*b[i - k]).sum::<f64>()/(i as f64);
+    }
+    b
+}
+
+/// returns true if x is even, false otherwise
+fn is_even(x: u32) -> bool {
+    x & 1 == 0
+}
+
+#[test]
+fn test_zeta_taylor() {
+    // zeta(2), zeta'(2), zeta''(2)/2
+    let z2 = zeta_taylor(2, 2);
+    assert!((z2[0] - 1.6449340668482264).abs() < 1e-15);
+    assert!((z2[1] + 0.93754825431584375).abs() < 1e-15);
+    assert!((z2[2] - 0.5*1.9892802342989010).abs() < 1e-15);
+
+    // Stieltjes constants gamma_0, -gamma_1
+    let z1 = zeta_taylor(1, 1);
+    assert!((z1[0] - EULER_GAMMA).abs() < 1e-15);
+    assert!((z1[1] - 0.072815845483676724).abs() < 1e-15);
+
+    // zeta(0) = -1/2, zeta'(0) = -ln(2 pi)/2
+    let z0 = zeta_taylor(0, 1);
+    assert!((z0[0] + 0.5).abs() < 1e-15);
+    assert!((z0[1] + 0.5*(2.0*std::f64::consts::PI).ln()).abs() < 1e-15);
+
+    // zeta(-1) = -1/12, zeta'(-1) = 1/12 - ln(A), zeta'(-2) = -zeta(3)/(4 pi^2)
+    let zm1 = zeta_taylor(-1, 1);
+    assert!((zm1[0] + 1.0/12.0).abs() < 1e-15);
+    assert!((zm1[1] + 0.16542114370045092).abs() < 1e-15);
+    let zm2 = zeta_taylor(-2, 1);
+    assert!(zm2[0].abs() < 1e-15);
+    assert!((zm2[1] + 0.030448457058393270).abs() < 1e-15);
+}
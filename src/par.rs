@@ -0,0 +1,269 @@
+//! Parallel evaluation of polylogarithms
+//!
+//! This module is available with the cargo feature `rayon`, which
+//! requires Rust 1.80 or later, and provides the parallel
+//! counterparts of the functions of the `batch` module for slices, as
+//! well as for `ndarray` arrays of arbitrary dimension, which are
+//! passed as views.  The work is distributed among the threads of the
+//! global rayon thread pool, where the minimum number of elements per
+//! task depends on the cost of a single call: cheap functions such as
+//! the real dilogarithm are evaluated in long chunks, expensive
+//! functions such as Li(n,z) in short ones.  The results are
+//! identical to the ones of the corresponding traits.
+//!
+//! # Example:
+//! ```
+//! use ndarray::Array2;
+//! use polylog::Li2;
+//! use polylog::par::{li2_array, li_slice};
+//!
+//! let x: Vec<f64> = (0..1000).map(|k| 0.001*(k as f64)).collect();
+//! let mut out = vec![0.0; x.len()];
+//! li_slice(10, &x, &mut out);
+//!
+//! let a = Array2::from_shape_fn((100, 100), |(i, j)| 0.01*((i + j) as f64));
+//! let mut b = Array2::zeros((100, 100));
+//! li2_array(a.view(), b.view_mut());
+//! assert!(b[[3, 4]] == a[[3, 4]].li2());
+//! ```
+
+use ndarray::{ArrayView, ArrayViewMut, Dimension, Zip};
+use num::complex::Complex;
+use rayon::prelude::*;
+use crate::{Li0, Li1, Li2, Li3, Li4, Li5, Li6};
+use crate::li::cli::cli_table;
+use crate::li::rli::rli_table;
+use crate::li::table::Table;
+
+// Minimum number of elements per task, such that a task takes a few
+// 10 microseconds and the scheduling overhead is negligible.
+
+/// minimum number of elements per task for Li0 and Li1
+const MIN_LEN_LI01: usize = 8192;
+/// minimum number of elements per task for Li2
+const MIN_LEN_LI2: usize = 2048;
+/// minimum number of elements per task for Li3 and Li4
+const MIN_LEN_LI34: usize = 1024;
+/// minimum number of elements per task for Li5, Li6 and Li(n)
+const MIN_LEN_LIN: usize = 256;
+
+/// applies f to all elements of x and writes the results to out in
+/// parallel
+fn map_slice<T, F>(name: &str, min_len: usize, x: &[T], out: &mut [T], f: F)
+where
+    T: Send + Sync,
+    F: Fn(&T) -> T + Send + Sync,
+{
+    if x.len() != out.len() {
+        panic!("{} requires slices of equal length (given lengths: {} and {})", name, x.len(), out.len());
+    }
+
+    out.par_iter_mut().zip(x.par_iter()).with_min_len(min_len).for_each(|(o, xi)| *o = f(xi));
+}
+
+/// applies f to all elements of x and writes the results to out in
+/// parallel
+fn map_array<T, D, F>(name: &str, min_len: usize, x: ArrayView<T, D>, out: ArrayViewMut<T, D>, f: F)
+where
+    T: Send + Sync,
+    D: Dimension,
+    F: Fn(&T) -> T + Send + Sync,
+{
+    if x.shape() != out.shape() {
+        panic!("{} requires arrays of equal shape (given shapes: {:?} and {:?})", name, x.shape(), out.shape());
+    }
+
+    Zip::from(out).and(x).into_par_iter().with_min_len(min_len).for_each(|(o, xi)| *o = f(xi));
+}
+
+/// Writes the 0th order polylogarithms Li0(x) of all elements of `x`
+/// to `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li0_slice<T: Li0<T> + Send + Sync>(x: &[T], out: &mut [T]) {
+    map_slice("li0_slice", MIN_LEN_LI01, x, out, |xi| xi.li0())
+}
+
+/// Writes the 1st order polylogarithms Li1(x) of all elements of `x`
+/// to `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li1_slice<T: Li1<T> + Send + Sync>(x: &[T], out: &mut [T]) {
+    map_slice("li1_slice", MIN_LEN_LI01, x, out, |xi| xi.li1())
+}
+
+/// Writes the dilogarithms Li2(x) of all elements of `x` to `out` in
+/// parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li2_slice<T: Li2<T> + Send + Sync>(x: &[T], out: &mut [T]) {
+    map_slice("li2_slice", MIN_LEN_LI2, x, out, |xi| xi.li2())
+}
+
+/// Writes the trilogarithms Li3(x) of all elements of `x` to `out` in
+/// parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li3_slice<T: Li3<T> + Send + Sync>(x: &[T], out: &mut [T]) {
+    map_slice("li3_slice", MIN_LEN_LI34, x, out, |xi| xi.li3())
+}
+
+/// Writes the 4th order polylogarithms Li4(x) of all elements of `x`
+/// to `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li4_slice<T: Li4<T> + Send + Sync>(x: &[T], out: &mut [T]) {
+    map_slice("li4_slice", MIN_LEN_LI34, x, out, |xi| xi.li4())
+}
+
+/// Writes the 5th order polylogarithms Li5(z) of all elements of `z`
+/// to `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li5_slice<T: Li5<T> + Send + Sync>(z: &[T], out: &mut [T]) {
+    map_slice("li5_slice", MIN_LEN_LIN, z, out, |zi| zi.li5())
+}
+
+/// Writes the 6th order polylogarithms Li6(z) of all elements of `z`
+/// to `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li6_slice<T: Li6<T> + Send + Sync>(z: &[T], out: &mut [T]) {
+    map_slice("li6_slice", MIN_LEN_LIN, z, out, |zi| zi.li6())
+}
+
+/// Writes the real n-th order polylogarithms Re[Li(n,x)] of all
+/// elements of `x` to `out` in parallel for a fixed integer order n.
+///
+/// The order-dependent coefficients are calculated only once and
+/// shared among the threads.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li_slice(n: i32, x: &[f64], out: &mut [f64]) {
+    let t = Table::new(n);
+    map_slice("li_slice", MIN_LEN_LIN, x, out, |xi| rli_table(&t, *xi))
+}
+
+/// Writes the complex n-th order polylogarithms Li(n,z) of all
+/// elements of `z` to `out` in parallel for a fixed integer order n.
+///
+/// The order-dependent coefficients are calculated only once and
+/// shared among the threads.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different lengths.
+pub fn li_slice_complex(n: i32, z: &[Complex<f64>], out: &mut [Complex<f64>]) {
+    let t = Table::new(n);
+    map_slice("li_slice_complex", MIN_LEN_LIN, z, out, |zi| cli_table(&t, *zi))
+}
+
+/// Writes the 0th order polylogarithms Li0(x) of all elements of the
+/// array `x` to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li0_array<T: Li0<T> + Send + Sync, D: Dimension>(x: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li0_array", MIN_LEN_LI01, x, out, |xi| xi.li0())
+}
+
+/// Writes the 1st order polylogarithms Li1(x) of all elements of the
+/// array `x` to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li1_array<T: Li1<T> + Send + Sync, D: Dimension>(x: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li1_array", MIN_LEN_LI01, x, out, |xi| xi.li1())
+}
+
+/// Writes the dilogarithms Li2(x) of all elements of the array `x` to
+/// the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li2_array<T: Li2<T> + Send + Sync, D: Dimension>(x: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li2_array", MIN_LEN_LI2, x, out, |xi| xi.li2())
+}
+
+/// Writes the trilogarithms Li3(x) of all elements of the array `x`
+/// to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li3_array<T: Li3<T> + Send + Sync, D: Dimension>(x: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li3_array", MIN_LEN_LI34, x, out, |xi| xi.li3())
+}
+
+/// Writes the 4th order polylogarithms Li4(x) of all elements of the
+/// array `x` to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li4_array<T: Li4<T> + Send + Sync, D: Dimension>(x: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li4_array", MIN_LEN_LI34, x, out, |xi| xi.li4())
+}
+
+/// Writes the 5th order polylogarithms Li5(z) of all elements of the
+/// array `z` to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different shapes.
+pub fn li5_array<T: Li5<T> + Send + Sync, D: Dimension>(z: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li5_array", MIN_LEN_LIN, z, out, |zi| zi.li5())
+}
+
+/// Writes the 6th order polylogarithms Li6(z) of all elements of the
+/// array `z` to the array `out` in parallel.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different shapes.
+pub fn li6_array<T: Li6<T> + Send + Sync, D: Dimension>(z: ArrayView<T, D>, out: ArrayViewMut<T, D>) {
+    map_array("li6_array", MIN_LEN_LIN, z, out, |zi| zi.li6())
+}
+
+/// Writes the real n-th order polylogarithms Re[Li(n,x)] of all
+/// elements of the array `x` to the array `out` in parallel for a
+/// fixed integer order n.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different shapes.
+pub fn li_array<D: Dimension>(n: i32, x: ArrayView<f64, D>, out: ArrayViewMut<f64, D>) {
+    let t = Table::new(n);
+    map_array("li_array", MIN_LEN_LIN, x, out, |xi| rli_table(&t, *xi))
+}
+
+/// Writes the complex n-th order polylogarithms Li(n,z) of all
+/// elements of the array `z` to the array `out` in parallel for a
+/// fixed integer order n.
+///
+/// # Panics
+///
+/// Panics if `z` and `out` have different shapes.
+pub fn li_array_complex<D: Dimension>(n: i32, z: ArrayView<Complex<f64>, D>, out: ArrayViewMut<Complex<f64>, D>) {
+    let t = Table::new(n);
+    map_array("li_array_complex", MIN_LEN_LIN, z, out, |zi| cli_table(&t, *zi))
+}
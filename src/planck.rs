@@ -0,0 +1,145 @@
+//! Partial Planck integrals
+//!
+//! P_n(x) = int(t=x:Inf, t^n/(e^t - 1))
+//!
+//! for integer n >= 0, and the fraction of the black body radiation
+//! emitted in a band of wavelengths.
+//!
+//! # Example:
+//! ```
+//! use polylog::planck::{band_fraction, planck_integral};
+//!
+//! // total radiation: int(t=0:Inf, t^3/(e^t - 1)) = pi^4/15
+//! let pi4 = std::f64::consts::PI.powi(4);
+//! assert!((planck_integral(3, 0.0) - pi4/15.0).abs() < 8.0*std::f64::EPSILON);
+//! assert!((band_fraction(0.0, f64::INFINITY) - 1.0).abs() < 2.0*std::f64::EPSILON);
+//! ```
+
+use crate::Li;
+use crate::debye::debye;
+use crate::li::fac::fac;
+use crate::li::zeta::zeta;
+use crate::quad::gauss_legendre;
+
+/// second radiation constant c_2 = h c/k in units of m K
+pub const C2: f64 = 1.438776877e-2;
+
+/// Returns the partial Planck integral
+///
+/// P_n(x) = int(t=x:Inf, t^n/(e^t - 1))
+///
+/// for integer n >= 0 and real x.  For n = 0, P_0(x) = -ln(1 - e^(-x))
+/// is only defined for x > 0.
+///
+/// For x >= 1 the integral is calculated from
+///
+/// P_n(x) = sum(k=0:n, n!/(n-k)! x^(n-k) Li(k+1,e^(-x)))
+///
+/// where all terms are positive.  For x > 40 the approximation
+/// Li(k+1,e^(-x)) = e^(-x) is used.  For x < 1 and n >= 1 the
+/// complement
+///
+/// P_n(x) = n! zeta(n+1) - x^n/n D_n(x)
+///
+/// is used, where D_n(x) is the Debye function.
+///
+/// # Panics
+///
+/// Panics if n < 0.
+pub fn planck_integral(n: i32, x: f64) -> f64 {
+    if n < 0 {
+        panic!("planck_integral not implemented for n < 0 (given value: n = {})", n);
+    }
+
+    if x.is_nan() {
+        f64::NAN
+    } else if x == f64::INFINITY {
+        0.0
+    } else if n == 0 {
+        if x < 0.0 {
+            f64::NAN
+        } else if x < std::f64::consts::LN_2 {
+            -(-(-x).exp_m1()).ln()
+        } else {
+            -(-(-x).exp()).ln_1p()
+        }
+    } else if x < 1.0 {
+        fac(n)*zeta(n + 1) - x.powi(n)/(n as f64)*debye(n, x)
+    } else if x > 40.0 {
+        planck_asymptotic(n, x)
+    } else {
+        let e = (-x).exp();
+        let mut p = x.powi(n); // collects n!/(n-k)! x^(n-k)
+        let mut sum = 0.0;
+
+        for k in 0..=n {
+            sum += p*e.li(k + 1);
+            p *= ((n - k) as f64)/x;
+        }
+
+        sum
+    }
+}
+
+/// Returns the fraction of the total black body radiation emitted in
+/// the interval x1 <= x <= x2 of the dimensionless variable
+/// x = h c/(lambda k T) = c_2/(lambda T),
+///
+/// f(x1,x2) = 15/pi^4 int(t=x1:x2, t^3/(e^t - 1))
+///
+/// for 0 <= x1 <= x2.  The result is negative for x1 > x2.
+///
+/// Narrow bands with x2 - x1 <= 1 are integrated numerically.  For
+/// wider bands the difference of the integrals over [x1,Inf) and
+/// [x2,Inf) or over [0,x2] and [0,x1] is taken, depending on which is
+/// less affected by cancellations.
+pub fn band_fraction(x1: f64, x2: f64) -> f64 {
+    let pi4 = std::f64::consts::PI.powi(4);
+
+    if x1.is_nan() || x2.is_nan() || x1 < 0.0 || x2 < 0.0 {
+        f64::NAN
+    } else if x1 > x2 {
+        -band_fraction(x2, x1)
+    } else if x2 - x1 <= 1.0 {
+        let f = |t: f64| if t == 0.0 { 0.0 } else { t*t*t/t.exp_m1() };
+        15.0/pi4*gauss_legendre(f, x1, x2)
+    } else if x1 > 3.5 {
+        // P_3(3.5) ~ pi^4/30
+        15.0/pi4*(planck_integral(3, x1) - planck_integral(3, x2))
+    } else {
+        // 15/pi^4 int(t=0:x, t^3/(e^t - 1)) = 5/pi^4 x^3 D_3(x)
+        let lower = |x: f64| if x == f64::INFINITY { 1.0 } else { 5.0/pi4*x*x*x*debye(3, x) };
+        lower(x2) - lower(x1)
+    }
+}
+
+/// Returns the fraction of the total black body radiation at the
+/// temperature t (in K) emitted in the band of wavelengths
+/// lambda1 <= lambda <= lambda2 (in m),
+///
+/// f = band_fraction(c_2/(lambda2 t), c_2/(lambda1 t))
+///
+/// with the second radiation constant c_2, see `C2`.
+pub fn band_fraction_wavelength(lambda1: f64, lambda2: f64, t: f64) -> f64 {
+    band_fraction(C2/(lambda2*t), C2/(lambda1*t))
+}
+
+/// returns P_n(x) for x > 40, where Li(k+1,e^(-x)) = e^(-x) to double
+/// precision:
+///
+/// P_n(x) = e^(-x) sum(k=0:n, n!/(n-k)! x^(n-k))
+///
+/// The factor e^(-x) is split into two factors e^(-x/2) to avoid an
+/// underflow.
+fn planck_asymptotic(n: i32, x: f64) -> f64 {
+    let h = (-0.5*x).exp();
+    let mut p = fac(n)*h; // collects n!/(n-k)! x^(n-k) e^(-x/2)
+    let mut sum = 0.0;
+
+    for k in (0..=n).rev() {
+        sum += p*h;
+        p *= x/((n - k + 1) as f64);
+    }
+
+    sum
+}
@@ -0,0 +1,164 @@
+use num::complex::Complex;
+
+/// 16-point Gauss-Legendre nodes x_i > 0 and weights w_i on [-1,1]
+const GAUSS_LEGENDRE_16: [(f64, f64); 8] = [
+    (0.09501250983763744, 0.1894506104550685  ),
+    (0.2816035507792589 , 0.18260341504492358 ),
+    (0.45801677765722737, 0.16915651939500254 ),
+    (0.6178762444026438 , 0.14959598881657674 ),
+    (0.755404408355003  , 0.12462897125553388 ),
+    (0.8656312023878318 , 0.09515851168249279 ),
+    (0.9445750230732326 , 0.062253523938647894),
+    (0.9894009349916499 , 0.027152459411754096),
+];
+
+/// returns the integral of f over [a,b] using the 16-point
+/// Gauss-Legendre quadrature rule
+pub fn gauss_legendre<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> f64 {
+    let c = 0.5*(a + b);
+    let h = 0.5*(b - a);
+    let mut sum = 0.0;
+
+    for &(x, w) in GAUSS_LEGENDRE_16.iter() {
+        sum += w*(f(c - h*x) + f(c + h*x));
+    }
+
+    h*sum
+}
+
+/// returns the integral of the complex-valued function f over [a,b]
+/// using the 16-point Gauss-Legendre quadrature rule
+pub fn gauss_legendre_complex<F: Fn(f64) -> Complex<f64>>(f: F, a: f64, b: f64) -> Complex<f64> {
+    let c = 0.5*(a + b);
+    let h = 0.5*(b - a);
+    let mut sum = Complex::new(0.0, 0.0);
+
+    for &(x, w) in GAUSS_LEGENDRE_16.iter() {
+        sum += w*(f(c - h*x) + f(c + h*x));
+    }
+
+    h*sum
+}
+
+/// returns the integral of f over [a,b] for 0 < a < b, where f may
+/// have a singularity at t = 0 and poles at a distance of at least
+/// pi from the real axis
+pub fn integrate<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut t = a;
+
+    while t < b {
+        let h = t.min(4.0).min(b - t);
+        sum += gauss_legendre(&f, t, t + h);
+        t += h;
+    }
+
+    sum
+}
+
+/// returns the integral of f over [a,Inf) for a > 0, where f may
+/// have a singularity at t = 0 and poles at a distance of at least
+/// pi from the real axis; the integration is stopped if t > tmin and
+/// the integral has converged
+pub fn integrate_tail<F: Fn(f64) -> f64>(f: F, a: f64, tmin: f64) -> f64 {
+    let mut sum = 0.0;
+    let mut t = a;
+
+    loop {
+        let h = t.min(4.0);
+        let chunk = gauss_legendre(&f, t, t + h);
+        sum += chunk;
+        t += h;
+        if t > tmin && (chunk.abs() <= 0.25*f64::EPSILON*sum.abs() || chunk == 0.0) { break; }
+    }
+
+    sum
+}
+
+/// returns the integral of f over [a,Inf) for a >= 0, where f may
+/// have a pole at t = mu and, if a > 0, a singularity at t = 0; for
+/// |Im(mu)| < c the path passes the pole on a triangle of height c on
+/// the side opposite to the pole (above the pole for Im(mu) = 0); the
+/// integration is stopped if t > tmin and the integral has converged
+pub fn integrate_pole<F: Fn(Complex<f64>) -> Complex<f64>>(f: F, a: f64, mu: Complex<f64>, c: f64, hmax: f64, tmin: f64) -> Complex<f64> {
+    // panels are at most half as long as the distance to the pole
+    let width = |t: Complex<f64>| {
+        let d = (t - mu).norm();
+        let h = if d > 0.0 { hmax.min(0.5*d) } else { hmax };
+        if a > 0.0 { h.min(t.norm()) } else { h }
+    };
+
+    let mut sum = Complex::new(0.0, 0.0);
+    let mut t = a;
+
+    if mu.re > a && mu.im.abs() < c {
+        let s = if mu.im > 0.0 { -1.0 } else { 1.0 };
+        let p = Complex::new((mu.re - c).max(a), 0.0);
+        let q = Complex::new(mu.re, s*c);
+        let r = Complex::new(mu.re + c, 0.0);
+        sum += integrate_line(&f, &width, Complex::new(a, 0.0), p);
+        sum += integrate_line(&f, &width, p, q);
+        sum += integrate_line(&f, &width, q, r);
+        t = r.re;
+    }
+
+    loop {
+        let h = width(Complex::new(t, 0.0));
+        let chunk = gauss_legendre_complex(|u| f(Complex::new(u, 0.0)), t, t + h);
+        sum += chunk;
+        t += h;
+        if !sum.is_finite() || (t > tmin && (chunk.norm() <= 0.25*f64::EPSILON*sum.norm() || chunk.norm() == 0.0)) {
+            break;
+        }
+    }
+
+    sum
+}
+
+/// returns the integral of f along the straight line from p to q,
+/// where the panel starting at t has the length width(t)
+fn integrate_line<F, W>(f: &F, width: &W, p: Complex<f64>, q: Complex<f64>) -> Complex<f64>
+where
+    F: Fn(Complex<f64>) -> Complex<f64>,
+    W: Fn(Complex<f64>) -> f64,
+{
+    let len = (q - p).norm();
+    let dir = (q - p)/len;
+    let mut sum = Complex::new(0.0, 0.0);
+    let mut u = 0.0;
+
+    while u < len {
+        let h = width(p + dir*u).min(len - u);
+        sum += dir*gauss_legendre_complex(|s| f(p + dir*s), u, u + h);
+        u += h;
+    }
+
+    sum
+}
+
+/// returns the height c of the triangle around a pole of order q at
+/// t = a + i 0 in `integrate_pole()` for an integrand
+/// t^m/(1 - e^(a-t))^q, which minimizes the magnitude
+/// |t|^m/|1 - e^(-i c)|^q at the top t = a + i c
+pub fn pole_height(m: f64, q: f64, a: f64) -> f64 {
+    let g = |c: f64| 0.5*m*(c/a).powi(2).ln_1p() - q*(2.0*(0.5*c).sin()).ln();
+    let mut best = (f64::INFINITY, 2.0);
+
+    for i in 1..=64 {
+        let c = f64::from(i)/32.0;
+        let gc = g(c);
+        if gc < best.0 {
+            best = (gc, c);
+        }
+    }
+
+    best.1
+}
+
+#[test]
+fn test_gauss_legendre() {
+    let eps = 1e-15;
+    assert!((gauss_legendre(|x| x*x, 0.0, 1.0) - 1.0/3.0).abs() < eps);
+    assert!((gauss_legendre(|x| x.exp(), 0.0, 1.0) - (1.0_f64.exp() - 1.0)).abs() < eps);
+    assert!((gauss_legendre(|x| x.powi(31), -1.0, 1.0)).abs() < eps);
+}
@@ -0,0 +1,207 @@
+//! Quantum dilogarithms
+//!
+//! The q-Pochhammer symbol (q-dilogarithm)
+//!
+//! (x;q)_Inf = prod(k=0:Inf, 1 - x q^k)
+//!
+//! for |q| < 1, and Faddeev's quantum dilogarithm
+//!
+//! Phi_b(z) = exp(int(w=R+i0, e^(-2izw)/(4 sinh(wb) sinh(w/b) w)))
+//!
+//! for real b and complex z, whose semiclassical limit is
+//! Phi_b(x/(2 pi b)) ~ exp(Li2(-e^x)/(2 pi i b^2)) for b -> 0.
+//!
+//! # Example:
+//! ```
+//! use num::complex::Complex;
+//! use polylog::quantum::{faddeev, q_pochhammer};
+//!
+//! let x = Complex::new(0.5, 0.0);
+//! let q = Complex::new(0.5, 0.0);
+//! assert!((q_pochhammer(x, q) - 0.2887880950866024).norm() < 4.0*std::f64::EPSILON);
+//!
+//! // Phi_b(0) = exp(i pi (b^2 + b^(-2))/24)
+//! let z = Complex::new(0.0, 0.0);
+//! let i = Complex::new(0.0, 1.0);
+//! assert!((faddeev(1.0, z) - (i*std::f64::consts::PI/12.0).exp()).norm() < 8.0*std::f64::EPSILON);
+//! ```
+
+use std::f64::consts::PI;
+use num::complex::Complex;
+use crate::{Li, Li2};
+use crate::cln::expm1;
+use crate::li::fac::inv_fac;
+use crate::numbers::bernoulli_f64;
+use crate::quad::gauss_legendre_complex;
+
+/// Returns the q-Pochhammer symbol
+///
+/// (x;q)_Inf = prod(k=0:Inf, 1 - x q^k)
+///
+/// for complex x and complex q with |q| < 1.  Returns NaN for
+/// |q| >= 1 and for infinite x.
+///
+/// The factors with |x q^k| > 1/2 are multiplied explicitly, the
+/// remaining product (y;q)_Inf with |y| <= 1/2 is calculated from
+///
+/// ln((y;q)_Inf) = -sum(m=1:Inf, y^m/(m (1 - q^m)))
+///
+/// The number of explicit factors grows like ln(2|x|)/(1 - |q|).
+pub fn q_pochhammer(x: Complex<f64>, q: Complex<f64>) -> Complex<f64> {
+    let one = Complex::new(1.0, 0.0);
+
+    if !x.is_finite() || q.is_nan() || q.norm() >= 1.0 {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let mut prod = one;
+    let mut y = x;
+
+    while y.norm() > 0.5 {
+        prod *= one - y;
+        y *= q;
+    }
+
+    let mut ym = one; // collects y^m
+    let mut qm = one; // collects q^m
+    let mut sum = Complex::new(0.0, 0.0);
+
+    for m in 1..i32::MAX {
+        ym *= y;
+        qm *= q;
+        let term = ym/((m as f64)*(one - qm));
+        sum += term;
+        if term.norm() <= 0.5*f64::EPSILON*sum.norm() { break; }
+    }
+
+    prod*(-sum).exp()
+}
+
+/// Returns Faddeev's quantum dilogarithm
+///
+/// Phi_b(z) = exp(int(w=R+i0, e^(-2izw)/(4 sinh(wb) sinh(w/b) w)))
+///
+/// for real b != 0 and complex z.
+///
+/// Phi_b(z) is invariant under b -> 1/b and b -> -b, such that b is
+/// mapped to 0 < b <= 1.  For Re(z) > 0 the inversion relation
+///
+/// Phi_b(z) Phi_b(-z) = Phi_b(0)^2 e^(i pi z^2),  Phi_b(0)^2 = e^(i pi (b^2 + b^(-2))/12)
+///
+/// is used, and Im(z) is shifted into the strip |Im(z)| <= c_b/2 with
+/// c_b = (b + 1/b)/2 by the functional equation
+///
+/// Phi_b(z - ib/2) = (1 + e^(2 pi b z)) Phi_b(z + ib/2)
+///
+/// The integral is then calculated numerically along the contour
+/// Im(w) = pi b/2, which separates the poles of the integrand at w = 0
+/// and w = i pi b.  For Re(z) < -45/(2 pi b) one has Phi_b(z) = 1 to
+/// double precision.
+///
+/// Returns NaN for b = 0.
+pub fn faddeev(b: f64, z: Complex<f64>) -> Complex<f64> {
+    let i = Complex::new(0.0, 1.0);
+
+    if b.is_nan() || b == 0.0 || z.is_nan() {
+        return Complex::new(f64::NAN, f64::NAN);
+    }
+
+    let b = b.abs().min(1.0/b.abs());
+
+    if z.re > 0.0 {
+        let phi02 = (i*PI*(b*b + 1.0/(b*b))/12.0).exp();
+        phi02*(i*PI*z*z).exp()/faddeev_left(b, -z)
+    } else {
+        faddeev_left(b, z)
+    }
+}
+
+/// Returns the asymptotic expansion of Faddeev's quantum dilogarithm
+/// for small b,
+///
+/// Phi_b(x/(2 pi b)) = exp(sum(n=0:Inf, (2 pi i b^2)^(2n-1) B_2n(1/2)/(2n)! Li(2-2n,-e^x)))
+///
+/// truncated after the term n = order, where B_2n(1/2) = (2^(1-2n) - 1) B_2n
+/// are the Bernoulli polynomials at 1/2.  The leading term n = 0 is
+/// the semiclassical limit exp(Li2(-e^x)/(2 pi i b^2)).
+pub fn faddeev_asymptotic(b: f64, x: Complex<f64>, order: u32) -> Complex<f64> {
+    let i = Complex::new(0.0, 1.0);
+    let y = -x.exp();
+    let h = 2.0*PI*b*b*i; // 2 pi i b^2
+    let h2 = h*h;
+    let mut p = 1.0/h; // collects (2 pi i b^2)^(2n-1)
+    let mut sum = p*y.li2();
+
+    for n in 1..=(order as i32) {
+        p *= h2;
+        let b2n = (2.0_f64.powi(1 - 2*n) - 1.0)*bernoulli_f64(2*n as u32);
+        sum += p*b2n*inv_fac(2*n)*y.li(2 - 2*n);
+    }
+
+    sum.exp()
+}
+
+/// returns Phi_b(z) for 0 < b <= 1 and Re(z) <= 0
+fn faddeev_left(b: f64, z: Complex<f64>) -> Complex<f64> {
+    let i = Complex::new(0.0, 1.0);
+    let one = Complex::new(1.0, 0.0);
+    let cb = 0.5*(b + 1.0/b);
+    let mut z = z;
+    let mut fac = one;
+
+    // shift Im(z) into [-c_b/2, c_b/2]
+    while z.im > 0.5*cb {
+        fac /= one + (2.0*PI*b*z - i*PI*b*b).exp();
+        z -= i*b;
+    }
+    while z.im < -0.5*cb {
+        fac *= one + (2.0*PI*b*z + i*PI*b*b).exp();
+        z += i*b;
+    }
+
+    if 2.0*PI*b*z.re < -45.0 {
+        fac
+    } else {
+        fac*ln_faddeev_int(b, z).exp()
+    }
+}
+
+/// returns ln(Phi_b(z)) for 0 < b <= 1, Re(z) <= 0 and
+/// |Im(z)| <= c_b/2 by numerical integration along w = u + i pi b/2
+fn ln_faddeev_int(b: f64, z: Complex<f64>) -> Complex<f64> {
+    let delta = 0.5*PI*b;
+    let c = b + 1.0/b;
+    let f = |u: f64| faddeev_integrand(b, z, Complex::new(u, delta));
+
+    // the integrand decays like e^(-r |u|)
+    let r = c - 2.0*z.im.abs();
+    let umax = (40.0 + 3.0*(1.0/b).ln())/r;
+    // the integrand oscillates with period pi/|Re(z)|
+    let hmax = (4.0/z.re.abs()).min(2.0);
+
+    let mut sum = Complex::new(0.0, 0.0);
+    let mut u = 0.0;
+
+    while u < umax {
+        let h = (0.5*u).max(delta).min(hmax);
+        sum += gauss_legendre_complex(f, u, u + h) + gauss_legendre_complex(f, -u - h, -u);
+        u += h;
+    }
+
+    sum
+}
+
+/// returns the integrand e^(-2izw)/(4 sinh(wb) sinh(w/b) w)
+fn faddeev_integrand(b: f64, z: Complex<f64>, w: Complex<f64>) -> Complex<f64> {
+    let i = Complex::new(0.0, 1.0);
+    let c = b + 1.0/b;
+
+    // 4 sinh(bw) sinh(w/b) = e^(cw) (1 - e^(-2bw)) (1 - e^(-2w/b)) for Re(w) >= 0
+    let a: Complex<f64> = -2.0*i*z*w;
+
+    if w.re >= 0.0 {
+        (a - c*w).exp()/(w*expm1(-2.0*b*w)*expm1(-2.0*w/b))
+    } else {
+        (a + c*w).exp()/(w*expm1(2.0*b*w)*expm1(2.0*w/b))
+    }
+}
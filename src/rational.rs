@@ -0,0 +1,58 @@
+//! Exact rational-function form of polylogarithms of non-positive
+//! order.
+//!
+//! For n >= 0 the polylogarithm Li(-n,z) is a rational function of z,
+//!
+//! Li(-n,z) = sum(k=0:(n-1), A(n,k) z^(k+1))/(1-z)^(n+1),
+//!
+//! where A(n,k) are the Eulerian numbers.
+//!
+//! # Example:
+//! ```
+//! use num::{BigInt, BigRational};
+//! use polylog::rational::{li_neg_exact, li_neg_rational};
+//!
+//! // Li(-2,z) = (z + z^2)/(1-z)^3
+//! let (p, d) = li_neg_rational(2);
+//! assert_eq!(p, vec![BigInt::from(0), BigInt::from(1), BigInt::from(1)]);
+//! assert_eq!(d, 3);
+//!
+//! let z = BigRational::new(BigInt::from(1), BigInt::from(2));
+//! assert_eq!(li_neg_exact(2, &z), Some(BigRational::from_integer(BigInt::from(6))));
+//! ```
+
+use num::{BigInt, BigRational, One, Zero};
+use crate::numbers::eulerian_row;
+
+/// Returns the numerator polynomial and the power of the denominator
+/// of the rational function Li(-n,z) = p(z)/(1-z)^d for n >= 0.
+///
+/// The numerator is returned as vector of coefficients [p_0, ..., p_m]
+/// of p(z) = sum(k=0:m, p_k z^k) with m = max(n,1), where p_0 = 0 and
+/// p_(k+1) = A(n,k) are the Eulerian numbers.  For n = 0 the vector
+/// has 2 elements, p(z) = z.  The power of the denominator is d = n + 1.
+pub fn li_neg_rational(n: u32) -> (Vec<BigInt>, u32) {
+    let mut p = Vec::with_capacity(n.max(1) as usize + 1);
+    p.push(BigInt::zero());
+    p.extend(eulerian_row(n));
+    (p, n + 1)
+}
+
+/// Returns the exact value of Li(-n,z) for n >= 0 and rational z.
+///
+/// Returns `None` for z = 1, where Li(-n,z) has a pole.
+pub fn li_neg_exact(n: u32, z: &BigRational) -> Option<BigRational> {
+    let one = BigRational::one();
+
+    if *z == one {
+        return None;
+    }
+
+    let (p, d) = li_neg_rational(n);
+    let num = p.iter().rev().fold(BigRational::zero(), |acc, c| {
+        acc*z + BigRational::from_integer(c.clone())
+    });
+    let den = num::pow(&one - z, d as usize);
+
+    Some(num/den)
+}
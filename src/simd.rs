@@ -0,0 +1,418 @@
+//! SIMD evaluation of the real dilogarithm and trilogarithm
+//!
+//! The functions of this module evaluate Re[Li2(x)] and Re[Li3(x)]
+//! for several real arguments x at once.  On x86_64 the SSE2 or AVX2
+//! instructions are selected at runtime, on other architectures the
+//! scalar functions `li2()` and `li3()` are used.  The AVX-512
+//! instructions are used only if the `avx512` feature is enabled,
+//! which requires Rust 1.89 or later.
+//!
+//! The vectorized code uses the same transformations and rational
+//! function approximations as `li2()` and `li3()`, but evaluates all
+//! branches without jumps and selects the result of every lane by
+//! masks.  The logarithms are calculated by a vectorized
+//! implementation of ln(x), which is accurate to less than 1 ulp.
+//! Therefore the results may differ from the ones of `li2()` and
+//! `li3()` by a few ulp.  For x > 2 the terms ln(x)^2/2 and
+//! ln(x)^3/6 cancel close to the zeros of Re[Li2(x)] at
+//! x = 12.5951703698450... and of Re[Li3(x)] at x = 85.1716733428841...,
+//! where only an absolute bound holds.  The difference is at most
+//! 4 ulp of max(|Li2(x)|, ln(x)^2/2) for Li2 and at most 7 ulp of
+//! max(|Li3(x)|, ln(x)^3/6) for Li3, where the second argument of max
+//! is used only for x > 2.  The special values at x = -1, 0, 1/2 and 1
+//! are identical.  All instruction sets give identical results.
+//!
+//! # Example:
+//! ```
+//! use polylog::Li2;
+//! use polylog::simd::li2_x4;
+//!
+//! let li = li2_x4([-2.0, 0.25, 0.75, 4.0]);
+//! assert!((li[1] - 0.25_f64.li2()).abs() <= 4.0*std::f64::EPSILON*0.25_f64.li2());
+//! ```
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(target_arch = "x86_64")]
+mod x86;
+
+/// Provides the operations of a vector of f64 lanes, which are needed
+/// for the branch-free evaluation of Li2 and Li3
+trait Vector: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self> {
+    type Mask: Copy;
+    const LANES: usize;
+
+    /// returns a vector with all lanes equal to x
+    fn splat(x: f64) -> Self;
+    /// returns the vector of the first LANES elements of x
+    fn load(x: &[f64]) -> Self;
+    /// writes the lanes to the first LANES elements of out
+    fn store(self, out: &mut [f64]);
+    /// returns the mask of self < o
+    fn lt(self, o: Self) -> Self::Mask;
+    /// returns the mask of self == o
+    fn eq(self, o: Self) -> Self::Mask;
+    /// returns a where m is set, b otherwise
+    fn select(m: Self::Mask, a: Self, b: Self) -> Self;
+    /// returns |self|
+    fn abs(self) -> Self;
+    /// returns (e, m) with self = 2^e m and m in [1,2) for positive
+    /// normal numbers
+    fn split_exp(self) -> (Self, Self);
+}
+
+/// returns ln(x) for positive x, translated from FDLIBM
+#[inline(always)]
+fn ln<V: Vector>(x: V) -> V {
+    let lg = [
+        6.666666666666735e-01, 3.999999999940942e-01,
+        2.857142874366239e-01, 2.2222198432149784e-01,
+        1.818357216161805e-01, 1.5313837699209373e-01,
+        1.4798198605116586e-01
+    ];
+    let ln2_hi = V::splat(6.931471803691238e-01);
+    let ln2_lo = V::splat(1.9082149292705877e-10);
+    let one = V::splat(1.0);
+
+    // reduce m to [sqrt(2)/2, sqrt(2))
+    let (e, m) = x.split_exp();
+    let big = V::splat(std::f64::consts::SQRT_2).lt(m);
+    let m = V::select(big, V::splat(0.5)*m, m);
+    let e = V::select(big, e + one, e);
+
+    let f = m - one;
+    let s = f/(V::splat(2.0) + f);
+    let z = s*s;
+    let w = z*z;
+    let t1 = w*(V::splat(lg[1]) + w*(V::splat(lg[3]) + w*V::splat(lg[5])));
+    let t2 = z*(V::splat(lg[0]) + w*(V::splat(lg[2]) + w*(V::splat(lg[4]) + w*V::splat(lg[6]))));
+    let r = t2 + t1;
+    let hfsq = V::splat(0.5)*f*f;
+    let res = e*ln2_hi - ((hfsq - (s*(hfsq + r) + e*ln2_lo)) - f);
+
+    V::select(x.eq(V::splat(f64::INFINITY)), x, res)
+}
+
+/// returns ln|1 - x|, where the rounding error of 1 - x is corrected
+/// for |x| <= 1
+#[inline(always)]
+fn ln_1m<V: Vector>(x: V) -> V {
+    let one = V::splat(1.0);
+    let w = one - x;
+    let c = ((one - w) - x)/w;
+    ln(w.abs()) + c
+}
+
+/// rational function approximation of Re[Li2(x)] for x in [0, 1/2],
+/// see `Li2Approx<f64>`
+#[inline(always)]
+fn li2_approx<V: Vector>(x: V) -> V {
+    let cp = [
+        1.0e+0,
+       -2.6883926818565422e+0,
+        2.647722269947311e+0,
+       -1.1538559607887415e+0,
+        2.0886077795020608e-1,
+       -1.0859777134152463e-2
+    ];
+    let cq = [
+        1.0e+0,
+       -2.9383926818565635e+0,
+        3.2712093293018634e+0,
+       -1.707670217395429e+0,
+        4.1596017228400606e-1,
+       -3.9801343754084485e-2,
+        8.274366897446666e-4
+    ];
+    let c = |v| V::splat(v);
+
+    let x2 = x*x;
+    let x4 = x2*x2;
+    let p = c(cp[0]) + x*c(cp[1]) + x2*(c(cp[2]) + x*c(cp[3])) +
+        x4*(c(cp[4]) + x*c(cp[5]));
+    let q = c(cq[0]) + x*c(cq[1]) + x2*(c(cq[2]) + x*c(cq[3])) +
+        x4*(c(cq[4]) + x*c(cq[5]) + x2*c(cq[6]));
+
+    x*p/q
+}
+
+/// returns Re[Li2(x)] for all lanes of x, see `Li2<f64>`
+#[inline(always)]
+fn li2<V: Vector>(x: V) -> V {
+    let z2 = std::f64::consts::PI*std::f64::consts::PI/6.0;
+    let c = |v| V::splat(v);
+    let one = c(1.0);
+
+    let lx = ln(x.abs());   // ln|x|
+    let l1 = ln_1m(x);      // ln|1 - x|
+
+    // argument of the rational function approximation in [0, 1/2]
+    let (num, den) = {
+        let (mut num, mut den) = (one, x);                 // x >= 1
+        let m = x.lt(c(1.0));
+        num = V::select(m, one - x, num);                  // x in [1/2,1)
+        den = V::select(m, one, den);
+        let m = x.lt(c(0.5));
+        num = V::select(m, x, num);                        // x in [0,1/2)
+        let m = x.lt(c(0.0));
+        den = V::select(m, x - one, den);                  // x in (-1,0)
+        let m = x.lt(c(-1.0));
+        num = V::select(m, one, num);                      // x < -1
+        den = V::select(m, one - x, den);
+        (num, den)
+    };
+    let q = num/den;
+    let y = V::select(x.lt(c(2.0)), one - q, q);           // x in (1,2)
+    let a = li2_approx(V::select(x.lt(one), q, y));
+
+    let mut res = -a + c(2.0*z2) - c(0.5)*lx*lx;
+    res = V::select(x.lt(c(2.0)), a + c(z2) - lx*((l1 - lx) + c(0.5)*lx), res);
+    res = V::select(x.eq(one), c(z2), res);
+    res = V::select(x.lt(one), -a + c(z2) - lx*l1, res);
+    res = V::select(x.lt(c(0.5)), a, res);
+    res = V::select(x.eq(c(0.0)), x, res);
+    res = V::select(x.lt(c(0.0)), -a - c(0.5)*l1*l1, res);
+    res = V::select(x.eq(-one), c(-0.5*z2), res);
+    res = V::select(x.lt(-one), a - c(z2) + l1*(c(0.5)*l1 - lx), res);
+
+    res
+}
+
+/// rational function approximation of Li3(x) for x in [-1,0], see
+/// `li3_neg()`
+#[inline(always)]
+fn li3_neg<V: Vector>(x: V) -> V {
+    let cp = [
+        1.0e+0, -2.028180175411713e+0,
+        1.4364029887561718e+0, -4.224068043571303e-1,
+        4.7296746450884095e-2, -1.3453536579918419e-3
+    ];
+    let cq = [
+        1.0e+0, -2.153180175411705e+0,
+        1.668513473646114e+0, -5.668485746458455e-1,
+        8.199946337062396e-2, -4.075604850292415e-3,
+        3.4316398489103214e-5
+    ];
+    let c = |v| V::splat(v);
+
+    let x2 = x*x;
+    let x4 = x2*x2;
+    let p = c(cp[0]) + x * c(cp[1]) + x2 * (c(cp[2]) + x * c(cp[3])) +
+            x4 * (c(cp[4]) + x * c(cp[5]));
+    let q = c(cq[0]) + x * c(cq[1]) + x2 * (c(cq[2]) + x * c(cq[3])) +
+            x4 * (c(cq[4]) + x * c(cq[5]) + x2 * c(cq[6]));
+
+    x*p/q
+}
+
+/// rational function approximation of Li3(x) for x in [0,1/2], see
+/// `li3_pos()`
+#[inline(always)]
+fn li3_pos<V: Vector>(x: V) -> V {
+    let cp = [
+        1.0e+0, -2.522471730376979e+0,
+        2.3204919140887896e+0, -9.398097328896504e-1,
+        1.572895020099051e-1, -7.548519398367707e-3
+    ];
+    let cq = [
+        1.0e+0, -2.6474717303769837e+0,
+        2.6143888433492184e+0, -1.1841788297857667e+0,
+        2.418493852479365e-1, -1.8220900115898157e-2,
+        2.4927971540017376e-4
+    ];
+    let c = |v| V::splat(v);
+
+    let x2 = x*x;
+    let x4 = x2*x2;
+    let p = c(cp[0]) + x * c(cp[1]) + x2 * (c(cp[2]) + x * c(cp[3])) +
+            x4 * (c(cp[4]) + x * c(cp[5]));
+    let q = c(cq[0]) + x * c(cq[1]) + x2 * (c(cq[2]) + x * c(cq[3])) +
+            x4 * (c(cq[4]) + x * c(cq[5]) + x2 * c(cq[6]));
+
+    x*p/q
+}
+
+/// returns Re[Li3(x)] for all lanes of x, see `Li3<f64>`
+#[inline(always)]
+fn li3<V: Vector>(x: V) -> V {
+    let z2 = 1.6449340668482264;
+    let z3 = 1.2020569031595943;
+    let c = |v| V::splat(v);
+    let one = c(1.0);
+    let zero = c(0.0);
+
+    let l = ln(x.abs());    // ln|x|
+    let l1 = ln_1m(x);      // ln|1 - x|
+    let r = one/x;
+
+    // arguments of li3_neg() and li3_pos(), 0 if not needed
+    let (an, ap) = {
+        let (mut an, mut ap) = (zero, r);                  // x >= 2
+        let m = x.lt(c(2.0));
+        an = V::select(m, one - x, an);                    // x in (1,2)
+        ap = V::select(m, one - r, ap);
+        let m = x.lt(one);
+        an = V::select(m, one - r, an);                    // x in (1/2,1)
+        ap = V::select(m, one - x, ap);
+        let m = x.lt(c(0.5));
+        an = V::select(m, zero, an);                       // x in [0,1/2)
+        ap = V::select(m, x, ap);
+        let m = x.lt(zero);
+        an = V::select(m, x, an);                          // x in [-1,0)
+        ap = V::select(m, zero, ap);
+        let m = x.lt(-one);
+        an = V::select(m, r, an);                          // x < -1
+        (an, ap)
+    };
+    let n = li3_neg(an);
+    let p = li3_pos(ap);
+
+    let mut res = p + l*(c(2.0*z2) - c(1.0/6.0)*l*l);
+    res = V::select(x.lt(c(2.0)), -n - p + c(z3) + l*(c(z2) + l*(c(-0.5)*l1 + c(1.0/6.0)*l)), res);
+    res = V::select(x.eq(one), c(z3), res);
+    res = V::select(x.eq(c(0.5)), c(0.5372131936080402), res);
+    res = V::select(x.lt(c(0.5)), p, res);
+    res = V::select(x.eq(zero), x, res);
+    res = V::select(x.lt(zero), n, res);
+    res = V::select(x.eq(-one), c(-0.75*z3), res);
+    res = V::select(x.lt(-one), n - l*(c(z2) + c(1.0/6.0)*l*l), res);
+
+    res
+}
+
+/// Provides the function, which is evaluated for all lanes
+///
+/// A static method is used instead of a closure, such that the
+/// function is inlined into the caller compiled with the target
+/// features.
+trait Kernel {
+    fn eval<V: Vector>(x: V) -> V;
+}
+
+/// evaluates Re[Li2(x)]
+struct Li2Kernel;
+
+impl Kernel for Li2Kernel {
+    #[inline(always)]
+    fn eval<V: Vector>(x: V) -> V { li2(x) }
+}
+
+/// evaluates Re[Li3(x)]
+struct Li3Kernel;
+
+impl Kernel for Li3Kernel {
+    #[inline(always)]
+    fn eval<V: Vector>(x: V) -> V { li3(x) }
+}
+
+/// applies the kernel K to all elements of x in chunks of V::LANES
+/// and writes the results to out, where the remainder is padded with
+/// zeros
+#[inline(always)]
+fn map_lanes<V: Vector, K: Kernel>(x: &[f64], out: &mut [f64]) {
+    let n = V::LANES;
+    let mut xc = x.chunks_exact(n);
+    let mut oc = out.chunks_exact_mut(n);
+
+    for (xi, oi) in (&mut xc).zip(&mut oc) {
+        K::eval(V::load(xi)).store(oi);
+    }
+
+    let xr = xc.remainder();
+    let or = oc.into_remainder();
+
+    if !xr.is_empty() {
+        let mut buf = [0.0; 8];
+        buf[..xr.len()].copy_from_slice(xr);
+        K::eval(V::load(&buf)).store(&mut buf);
+        or.copy_from_slice(&buf[..xr.len()]);
+    }
+}
+
+/// checks that the slices have equal lengths
+fn check_len(name: &str, x: &[f64], out: &[f64]) {
+    if x.len() != out.len() {
+        panic!("{} requires slices of equal length (given lengths: {} and {})", name, x.len(), out.len());
+    }
+}
+
+/// Writes the real dilogarithms Re[Li2(x)] of all elements of `x` to
+/// `out` using SIMD instructions, if available.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+///
+/// # Example:
+/// ```
+/// use polylog::Li2;
+/// use polylog::simd::li2_slice;
+///
+/// let x: Vec<f64> = (0..100).map(|i| 0.1*(i as f64) - 5.0).collect();
+/// let mut out = vec![0.0; x.len()];
+/// li2_slice(&x, &mut out);
+/// assert!((out[10] - x[10].li2()).abs() < 1e-14);
+/// ```
+pub fn li2_slice(x: &[f64], out: &mut [f64]) {
+    check_len("li2_slice", x, out);
+
+    #[cfg(target_arch = "x86_64")]
+    x86::li2_slice(x86::detect(), x, out);
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        use crate::Li2;
+        for (o, xi) in out.iter_mut().zip(x.iter()) {
+            *o = xi.li2();
+        }
+    }
+}
+
+/// Writes the real trilogarithms Re[Li3(x)] of all elements of `x` to
+/// `out` using SIMD instructions, if available.
+///
+/// # Panics
+///
+/// Panics if `x` and `out` have different lengths.
+pub fn li3_slice(x: &[f64], out: &mut [f64]) {
+    check_len("li3_slice", x, out);
+
+    #[cfg(target_arch = "x86_64")]
+    x86::li3_slice(x86::detect(), x, out);
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        use crate::Li3;
+        for (o, xi) in out.iter_mut().zip(x.iter()) {
+            *o = xi.li3();
+        }
+    }
+}
+
+/// Returns the real dilogarithms Re[Li2(x)] of 4 real numbers.
+pub fn li2_x4(x: [f64; 4]) -> [f64; 4] {
+    let mut out = [0.0; 4];
+    li2_slice(&x, &mut out);
+    out
+}
+
+/// Returns the real dilogarithms Re[Li2(x)] of 8 real numbers.
+pub fn li2_x8(x: [f64; 8]) -> [f64; 8] {
+    let mut out = [0.0; 8];
+    li2_slice(&x, &mut out);
+    out
+}
+
+/// Returns the real trilogarithms Re[Li3(x)] of 4 real numbers.
+pub fn li3_x4(x: [f64; 4]) -> [f64; 4] {
+    let mut out = [0.0; 4];
+    li3_slice(&x, &mut out);
+    out
+}
+
+/// Returns the real trilogarithms Re[Li3(x)] of 8 real numbers.
+pub fn li3_x8(x: [f64; 8]) -> [f64; 8] {
+    let mut out = [0.0; 8];
+    li3_slice(&x, &mut out);
+    out
+}
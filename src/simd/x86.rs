@@ -0,0 +1,300 @@
+// The AVX-512 intrinsics require Rust 1.89, which is above the MSRV,
+// therefore they are only compiled with the `avx512` feature.
+#![cfg_attr(feature = "avx512", allow(clippy::incompatible_msrv))]
+
+use std::arch::x86_64::*;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use super::{Li2Kernel, Li3Kernel, Vector, map_lanes};
+
+// The vector types below must only be used in functions, which are
+// compiled with the corresponding target features and which are
+// called only if these features have been detected at runtime.
+
+/// instruction set extension used for the evaluation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Level {
+    Sse2,
+    Avx2,
+    #[cfg(feature = "avx512")]
+    Avx512,
+}
+
+/// returns the best instruction set extension available at runtime
+pub(super) fn detect() -> Level {
+    #[cfg(feature = "avx512")]
+    {
+        if is_x86_feature_detected!("avx512f") {
+            return Level::Avx512;
+        }
+    }
+    if is_x86_feature_detected!("avx2") {
+        Level::Avx2
+    } else {
+        Level::Sse2
+    }
+}
+
+/// 2 lanes of f64 (SSE2)
+#[derive(Clone, Copy)]
+struct F64x2(__m128d);
+
+/// 4 lanes of f64 (AVX2)
+#[derive(Clone, Copy)]
+struct F64x4(__m256d);
+
+/// 8 lanes of f64 (AVX-512)
+#[cfg(feature = "avx512")]
+#[derive(Clone, Copy)]
+struct F64x8(__m512d);
+
+macro_rules! impl_ops {
+    ($t:ident, $add:ident, $sub:ident, $mul:ident, $div:ident) => {
+        impl Add for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn add(self, o: $t) -> $t { $t(unsafe { $add(self.0, o.0) }) }
+        }
+
+        impl Sub for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn sub(self, o: $t) -> $t { $t(unsafe { $sub(self.0, o.0) }) }
+        }
+
+        impl Mul for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn mul(self, o: $t) -> $t { $t(unsafe { $mul(self.0, o.0) }) }
+        }
+
+        impl Div for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn div(self, o: $t) -> $t { $t(unsafe { $div(self.0, o.0) }) }
+        }
+
+        impl Neg for $t {
+            type Output = $t;
+            #[inline(always)]
+            fn neg(self) -> $t { $t::splat(-0.0).xor(self) }
+        }
+    };
+}
+
+impl_ops!(F64x2, _mm_add_pd, _mm_sub_pd, _mm_mul_pd, _mm_div_pd);
+impl_ops!(F64x4, _mm256_add_pd, _mm256_sub_pd, _mm256_mul_pd, _mm256_div_pd);
+#[cfg(feature = "avx512")]
+impl_ops!(F64x8, _mm512_add_pd, _mm512_sub_pd, _mm512_mul_pd, _mm512_div_pd);
+
+/// bits of the mantissa
+const MANT: i64 = 0x000F_FFFF_FFFF_FFFF;
+
+/// bits of 2^52, such that (2^52 + e) - (2^52 + 1023) = e - 1023
+const TWO52: i64 = 0x4330_0000_0000_0000;
+
+impl F64x2 {
+    #[inline(always)]
+    fn xor(self, o: F64x2) -> F64x2 { F64x2(unsafe { _mm_xor_pd(self.0, o.0) }) }
+}
+
+impl Vector for F64x2 {
+    type Mask = __m128d;
+    const LANES: usize = 2;
+
+    #[inline(always)]
+    fn splat(x: f64) -> Self { F64x2(unsafe { _mm_set1_pd(x) }) }
+
+    #[inline(always)]
+    fn load(x: &[f64]) -> Self { F64x2(unsafe { _mm_loadu_pd(x[..2].as_ptr()) }) }
+
+    #[inline(always)]
+    fn store(self, out: &mut [f64]) { unsafe { _mm_storeu_pd(out[..2].as_mut_ptr(), self.0) } }
+
+    #[inline(always)]
+    fn lt(self, o: Self) -> __m128d { unsafe { _mm_cmplt_pd(self.0, o.0) } }
+
+    #[inline(always)]
+    fn eq(self, o: Self) -> __m128d { unsafe { _mm_cmpeq_pd(self.0, o.0) } }
+
+    #[inline(always)]
+    fn select(m: __m128d, a: Self, b: Self) -> Self {
+        F64x2(unsafe { _mm_or_pd(_mm_and_pd(m, a.0), _mm_andnot_pd(m, b.0)) })
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self { F64x2(unsafe { _mm_andnot_pd(_mm_set1_pd(-0.0), self.0) }) }
+
+    #[inline(always)]
+    fn split_exp(self) -> (Self, Self) {
+        unsafe {
+            let bits = _mm_castpd_si128(self.0);
+            let eb = _mm_or_si128(_mm_srli_epi64::<52>(bits), _mm_set1_epi64x(TWO52));
+            let e = _mm_sub_pd(_mm_castsi128_pd(eb), _mm_set1_pd(4503599627371519.0));
+            let m = _mm_or_si128(_mm_and_si128(bits, _mm_set1_epi64x(MANT)), _mm_castpd_si128(_mm_set1_pd(1.0)));
+            (F64x2(e), F64x2(_mm_castsi128_pd(m)))
+        }
+    }
+}
+
+impl F64x4 {
+    #[inline(always)]
+    fn xor(self, o: F64x4) -> F64x4 { F64x4(unsafe { _mm256_xor_pd(self.0, o.0) }) }
+}
+
+impl Vector for F64x4 {
+    type Mask = __m256d;
+    const LANES: usize = 4;
+
+    #[inline(always)]
+    fn splat(x: f64) -> Self { F64x4(unsafe { _mm256_set1_pd(x) }) }
+
+    #[inline(always)]
+    fn load(x: &[f64]) -> Self { F64x4(unsafe { _mm256_loadu_pd(x[..4].as_ptr()) }) }
+
+    #[inline(always)]
+    fn store(self, out: &mut [f64]) { unsafe { _mm256_storeu_pd(out[..4].as_mut_ptr(), self.0) } }
+
+    #[inline(always)]
+    fn lt(self, o: Self) -> __m256d { unsafe { _mm256_cmp_pd::<_CMP_LT_OQ>(self.0, o.0) } }
+
+    #[inline(always)]
+    fn eq(self, o: Self) -> __m256d { unsafe { _mm256_cmp_pd::<_CMP_EQ_OQ>(self.0, o.0) } }
+
+    #[inline(always)]
+    fn select(m: __m256d, a: Self, b: Self) -> Self {
+        F64x4(unsafe { _mm256_blendv_pd(b.0, a.0, m) })
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self { F64x4(unsafe { _mm256_andnot_pd(_mm256_set1_pd(-0.0), self.0) }) }
+
+    #[inline(always)]
+    fn split_exp(self) -> (Self, Self) {
+        unsafe {
+            let bits = _mm256_castpd_si256(self.0);
+            let eb = _mm256_or_si256(_mm256_srli_epi64::<52>(bits), _mm256_set1_epi64x(TWO52));
+            let e = _mm256_sub_pd(_mm256_castsi256_pd(eb), _mm256_set1_pd(4503599627371519.0));
+            let m = _mm256_or_si256(_mm256_and_si256(bits, _mm256_set1_epi64x(MANT)), _mm256_castpd_si256(_mm256_set1_pd(1.0)));
+            (F64x4(e), F64x4(_mm256_castsi256_pd(m)))
+        }
+    }
+}
+
+#[cfg(feature = "avx512")]
+impl F64x8 {
+    #[inline(always)]
+    fn xor(self, o: F64x8) -> F64x8 {
+        F64x8(unsafe { _mm512_castsi512_pd(_mm512_xor_si512(_mm512_castpd_si512(self.0), _mm512_castpd_si512(o.0))) })
+    }
+}
+
+#[cfg(feature = "avx512")]
+impl Vector for F64x8 {
+    type Mask = __mmask8;
+    const LANES: usize = 8;
+
+    #[inline(always)]
+    fn splat(x: f64) -> Self { F64x8(unsafe { _mm512_set1_pd(x) }) }
+
+    #[inline(always)]
+    fn load(x: &[f64]) -> Self { F64x8(unsafe { _mm512_loadu_pd(x[..8].as_ptr()) }) }
+
+    #[inline(always)]
+    fn store(self, out: &mut [f64]) { unsafe { _mm512_storeu_pd(out[..8].as_mut_ptr(), self.0) } }
+
+    #[inline(always)]
+    fn lt(self, o: Self) -> __mmask8 { unsafe { _mm512_cmp_pd_mask::<_CMP_LT_OQ>(self.0, o.0) } }
+
+    #[inline(always)]
+    fn eq(self, o: Self) -> __mmask8 { unsafe { _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(self.0, o.0) } }
+
+    #[inline(always)]
+    fn select(m: __mmask8, a: Self, b: Self) -> Self {
+        F64x8(unsafe { _mm512_mask_blend_pd(m, b.0, a.0) })
+    }
+
+    #[inline(always)]
+    fn abs(self) -> Self { F64x8(unsafe { _mm512_abs_pd(self.0) }) }
+
+    #[inline(always)]
+    fn split_exp(self) -> (Self, Self) {
+        unsafe {
+            let bits = _mm512_castpd_si512(self.0);
+            let eb = _mm512_or_si512(_mm512_srli_epi64::<52>(bits), _mm512_set1_epi64(TWO52));
+            let e = _mm512_sub_pd(_mm512_castsi512_pd(eb), _mm512_set1_pd(4503599627371519.0));
+            let m = _mm512_or_si512(_mm512_and_si512(bits, _mm512_set1_epi64(MANT)), _mm512_castpd_si512(_mm512_set1_pd(1.0)));
+            (F64x8(e), F64x8(_mm512_castsi512_pd(m)))
+        }
+    }
+}
+
+#[target_feature(enable = "sse2")]
+unsafe fn li2_sse2(x: &[f64], out: &mut [f64]) { map_lanes::<F64x2, Li2Kernel>(x, out) }
+
+#[target_feature(enable = "avx2")]
+unsafe fn li2_avx2(x: &[f64], out: &mut [f64]) { map_lanes::<F64x4, Li2Kernel>(x, out) }
+
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+unsafe fn li2_avx512(x: &[f64], out: &mut [f64]) { map_lanes::<F64x8, Li2Kernel>(x, out) }
+
+#[target_feature(enable = "sse2")]
+unsafe fn li3_sse2(x: &[f64], out: &mut [f64]) { map_lanes::<F64x2, Li3Kernel>(x, out) }
+
+#[target_feature(enable = "avx2")]
+unsafe fn li3_avx2(x: &[f64], out: &mut [f64]) { map_lanes::<F64x4, Li3Kernel>(x, out) }
+
+#[cfg(feature = "avx512")]
+#[target_feature(enable = "avx512f")]
+unsafe fn li3_avx512(x: &[f64], out: &mut [f64]) { map_lanes::<F64x8, Li3Kernel>(x, out) }
+
+/// writes Re[Li2(x)] to out using the given instruction set, which
+/// must be available
+pub(super) fn li2_slice(level: Level, x: &[f64], out: &mut [f64]) {
+    unsafe {
+        match level {
+            Level::Sse2 => li2_sse2(x, out),
+            Level::Avx2 => li2_avx2(x, out),
+            #[cfg(feature = "avx512")]
+            Level::Avx512 => li2_avx512(x, out),
+        }
+    }
+}
+
+/// writes Re[Li3(x)] to out using the given instruction set, which
+/// must be available
+pub(super) fn li3_slice(level: Level, x: &[f64], out: &mut [f64]) {
+    unsafe {
+        match level {
+            Level::Sse2 => li3_sse2(x, out),
+            Level::Avx2 => li3_avx2(x, out),
+            #[cfg(feature = "avx512")]
+            Level::Avx512 => li3_avx512(x, out),
+        }
+    }
+}
+
+#[test]
+fn test_levels() {
+    use crate::{Li2, Li3};
+
+    let mut levels = vec![Level::Sse2];
+    if is_x86_feature_detected!("avx2") { levels.push(Level::Avx2); }
+    #[cfg(feature = "avx512")]
+    if is_x86_feature_detected!("avx512f") { levels.push(Level::Avx512); }
+
+    let x: Vec<f64> = (-2000..=2000).map(|i| 0.01*(i as f64) + 0.0001).collect();
+    let mut out = vec![0.0; x.len()];
+    let mut first2 = vec![];
+    let mut first3 = vec![];
+
+    for (k, &level) in levels.iter().enumerate() {
+        li2_slice(level, &x, &mut out);
+        assert!(x.iter().zip(out.iter()).all(|(x, l)| (l - x.li2()).abs() <= 1e-14*x.li2().abs().max(1.0)));
+        if k == 0 { first2 = out.clone(); } else { assert!(out == first2); }
+
+        li3_slice(level, &x, &mut out);
+        assert!(x.iter().zip(out.iter()).all(|(x, l)| (l - x.li3()).abs() <= 1e-14*x.li3().abs().max(1.0)));
+        if k == 0 { first3 = out.clone(); } else { assert!(out == first3); }
+    }
+}
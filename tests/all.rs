@@ -0,0 +1,53 @@
+use num::complex::Complex;
+use polylog::{Li, LiAll};
+mod common;
+
+#[test]
+fn test_identical_to_li() {
+    let nmax = 12;
+
+    for i in -30..=30 {
+        let x = 0.1*(i as f64) + 0.01;
+        let li = x.li_all(nmax);
+        assert_eq!(li.len(), nmax as usize);
+        for k in 1..=nmax {
+            assert!(li[(k - 1) as usize] == x.li(k));
+        }
+
+        for j in -30..=30 {
+            let z = Complex::new(x, 0.1*(j as f64));
+            let li = z.li_all(nmax);
+            assert_eq!(li.len(), nmax as usize);
+            for k in 1..=nmax {
+                assert!(li[(k - 1) as usize] == z.li(k));
+            }
+        }
+    }
+
+    // unit circle
+    for i in 0..100 {
+        let z = Complex::from_polar(1.0, 0.0314*(i as f64) + 0.001);
+        let li = z.li_all(nmax);
+        for k in 1..=nmax {
+            assert!(li[(k - 1) as usize] == z.li(k));
+        }
+    }
+}
+
+#[test]
+fn test_special_values() {
+    assert!(0.5_f64.li_all(0).is_empty());
+    assert!(Complex::new(0.5, 0.5).li_all(-3).is_empty());
+    assert_eq!(Complex::new(0.5, 0.5).li_all(1), vec![Complex::new(0.5, 0.5).li(1)]);
+
+    let z = Complex::new(0.0, 0.0);
+    assert!(z.li_all(8).iter().all(|l| *l == z));
+
+    let li = Complex::new(1.0, 0.0).li_all(3);
+    assert!(li[0].re.is_infinite());
+    assert_eq_float!(li[1].re, 1.6449340668482264_f64, 1e-15_f64);
+    assert_eq_float!(li[2].re, 1.2020569031595943_f64, 1e-15_f64);
+
+    assert!(Complex::new(std::f64::NAN, 1.0).li_all(8).iter().all(|l| l.is_nan()));
+    assert!(std::f64::NAN.li_all(8).iter().all(|l| l.is_nan()));
+}
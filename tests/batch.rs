@@ -0,0 +1,83 @@
+use num::complex::Complex;
+use polylog::{Li, Li1, Li2, Li3, Li4, Li5, Li6};
+use polylog::batch::*;
+mod common;
+use common::same;
+
+fn grid_real() -> Vec<f64> {
+    let mut x: Vec<f64> = (-100..=100).map(|i| 0.05*(i as f64) + 0.001).collect();
+    x.extend([0.0, 1.0, -1.0, 0.99, -0.99, 1e10, -1e10, 1e-10, std::f64::NAN].iter());
+    x
+}
+
+fn grid_complex() -> Vec<Complex<f64>> {
+    let mut z = vec![];
+    for i in -30..=30 {
+        for j in -30..=30 {
+            z.push(Complex::new(0.1*(i as f64) + 0.001, 0.1*(j as f64)));
+        }
+    }
+    for i in 0..50 {
+        z.push(Complex::from_polar(1.0, 0.1*(i as f64) - 2.5));
+    }
+    z.extend([Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+              Complex::new(std::f64::NAN, 1.0), Complex::new(std::f64::INFINITY, 0.0)].iter());
+    z
+}
+
+#[test]
+fn test_fixed_order_slices() {
+    let x = grid_real();
+    let mut out = vec![0.0; x.len()];
+
+    li2_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li2())));
+    li3_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li3())));
+    li4_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li4())));
+
+    let z = grid_complex();
+    let mut out = vec![Complex::new(0.0, 0.0); z.len()];
+
+    li1_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same(l.re, z.li1().re) && same(l.im, z.li1().im)));
+    li5_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same(l.re, z.li5().re) && same(l.im, z.li5().im)));
+    li6_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same(l.re, z.li6().re) && same(l.im, z.li6().im)));
+}
+
+#[test]
+fn test_li_slice() {
+    let x = grid_real();
+    let z = grid_complex();
+
+    for &n in [-10, -3, -1, 0, 1, 2, 3, 4, 5, 6, 7, 10, 19, 20, 25, 100, 1000, 100_000_000, i32::MAX - 100].iter() {
+        let mut out = vec![0.0; x.len()];
+        li_slice(n, &x, &mut out);
+        for (x, l) in x.iter().zip(out.iter()) {
+            assert!(same(*l, x.li(n)), "n = {}, x = {}", n, x);
+        }
+
+        let li: Vec<f64> = li_iter(n, x.iter().cloned()).collect();
+        assert!(li.iter().zip(out.iter()).all(|(a, b)| same(*a, *b)));
+
+        let mut out = vec![Complex::new(0.0, 0.0); z.len()];
+        li_slice_complex(n, &z, &mut out);
+        for (z, l) in z.iter().zip(out.iter()) {
+            let li = z.li(n);
+            assert!(same(l.re, li.re) && same(l.im, li.im), "n = {}, z = {}", n, z);
+        }
+
+        let li: Vec<Complex<f64>> = li_iter_complex(n, z.iter().cloned()).collect();
+        assert!(li.iter().zip(out.iter()).all(|(a, b)| same(a.re, b.re) && same(a.im, b.im)));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_length_mismatch() {
+    let mut out = [0.0; 2];
+    li_slice(7, &[0.5, 0.6, 0.7], &mut out);
+}
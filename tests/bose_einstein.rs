@@ -0,0 +1,89 @@
+use polylog::Li;
+use polylog::bose_einstein::{bose_einstein, bose_einstein_inv};
+mod common;
+
+
+#[test]
+fn test_values() {
+    struct Be { s: f64, z: f64, res: f64 }
+
+    let values = vec![
+        Be { s:   1.5, z:            0.5, res: 0.62483702081991385_f64 },
+        Be { s:   1.5, z:          0.999, res: 2.5017084653413556_f64 },
+        Be { s:   1.5, z:   0.9999999999, res: 2.6123398997530383_f64 },
+        Be { s:   1.5, z:            1.0, res: 2.6123753486854883_f64 },
+        Be { s:   2.5, z:            0.9, res: 1.1390030252021568_f64 },
+        Be { s:   2.5, z:            1.0, res: 1.3414872572509172_f64 },
+        Be { s:   0.5, z:            0.3, res: 0.38477744513420899_f64 },
+        Be { s:   0.5, z:           0.99, res: 16.221830753428103_f64 },
+        Be { s:  -0.5, z:            0.7, res: 3.9621190283786068_f64 },
+        Be { s:  -1.5, z:           0.95, res: 2230.8977819312819_f64 },
+        Be { s:  -2.5, z:            0.5, res: 11.991219584288189_f64 },
+        Be { s:   3.5, z:           -2.0, res: -1.7498330394987238_f64 },
+        Be { s:   0.5, z:           -0.5, res: -0.37375223798097306_f64 },
+        Be { s:   5.5, z:       0.999999, res: 1.0252035252472109_f64 },
+        Be { s:   1.5, z:          1e-05, res: 1.0000035355531511e-5_f64 },
+        Be { s:   2.0, z:            0.9, res: 1.2997147230049588_f64 },
+        Be { s:  -1.0, z:            0.5, res: 2.0_f64 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(bose_einstein(v.s, v.z), v.res, 1e-14_f64);
+    }
+}
+
+
+#[test]
+fn test_integer_order() {
+    for n in -5..10 {
+        for k in -40..40 {
+            let z = 0.025*(k as f64);
+            assert!(bose_einstein(n as f64, z) == z.li(n));
+        }
+    }
+}
+
+
+#[test]
+fn test_limits() {
+    assert!(bose_einstein(1.5, f64::NAN).is_nan());
+    assert!(bose_einstein(1.5, 1.5).is_nan());
+    assert!(bose_einstein(2.0, 1.5).is_nan());
+    assert!(bose_einstein(1.5, 0.0) == 0.0);
+    assert!(bose_einstein(1.0, 1.0) == f64::INFINITY);
+    assert!(bose_einstein(0.5, 1.0) == f64::INFINITY);
+    assert!(bose_einstein(-2.0, 1.0) == f64::INFINITY);
+    assert!(bose_einstein(2.0, 1.0) == 1.6449340668482264);
+}
+
+
+#[test]
+fn test_inverse() {
+    for &s in [-2.5_f64, -2.0, -0.5, 0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.5].iter() {
+        for &z in [1e-300_f64, 1e-10, 0.01, 0.3, 0.5, 0.9, 0.999, 1.0 - 1e-9, 1.0 - 1e-13].iter() {
+            let g = bose_einstein(s, z);
+            let zi = bose_einstein_inv(s, g);
+            assert_eq_float!(zi/z, 1.0_f64, 1e-14_f64);
+        }
+    }
+}
+
+
+#[test]
+fn test_inverse_limits() {
+    let zeta32 = bose_einstein(1.5, 1.0);
+    assert!(bose_einstein_inv(1.5, zeta32) == 1.0);
+    assert!(bose_einstein_inv(1.5, 1.01*zeta32).is_nan());
+    assert!(bose_einstein_inv(1.5, -1.0).is_nan());
+    assert!(bose_einstein_inv(1.5, f64::NAN).is_nan());
+    assert!(bose_einstein_inv(1.5, 0.0) == 0.0);
+    assert!(bose_einstein_inv(0.5, 1e10) < 1.0);
+    assert!(bose_einstein_inv(0.5, f64::INFINITY) == 1.0);
+}
+
+
+#[test]
+#[should_panic]
+fn test_non_half_integer_order() {
+    bose_einstein(0.25, 0.5);
+}
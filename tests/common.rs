@@ -26,6 +26,7 @@ macro_rules! assert_eq_complex {
 }
 
 
+#[allow(dead_code)]
 pub trait CLn<T> {
     fn cln(&self) -> T;
 }
@@ -41,6 +42,7 @@ impl CLn<Complex<f64>> for Complex<f64> {
 }
 
 
+#[allow(dead_code)]
 fn data_path(filename: &str) -> PathBuf {
     let mut path = PathBuf::from(file!());
     path.pop();
@@ -53,6 +55,7 @@ fn data_path(filename: &str) -> PathBuf {
 }
 
 
+#[allow(dead_code)]
 pub fn read_data_file<T: Copy + FromStr>(filename: &str) -> Result<Vec<(Complex<T>, Complex<T>)>, Error> where <T as FromStr>::Err: Debug {
     let file = File::open(data_path(filename))?;
     let br = BufReader::new(file);
@@ -73,3 +76,10 @@ pub fn read_data_file<T: Copy + FromStr>(filename: &str) -> Result<Vec<(Complex<
 
     Ok(vec)
 }
+
+
+/// equality including NaN
+#[allow(dead_code)]
+pub fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
@@ -0,0 +1,140 @@
+use num::complex::Complex;
+use polylog::{Li, Li2, LiComplement};
+mod common;
+
+#[test]
+fn test_values() {
+    struct Lr { n: i32, d: f64, res: f64 }
+    struct Lc { n: i32, d: Complex<f64>, res: Complex<f64> }
+
+    let lr = vec![
+        Lr { n: -2, d: 1e-15, res: 1.9999999999999965e45 },
+        Lr { n: -2, d: -1e-10, res: -2.0000000002999998e30 },
+        Lr { n: -2, d: 1e-5, res: 1.9999700000999995e15 },
+        Lr { n: -2, d: 0.3, res: 44.07407407407408 },
+        Lr { n: -2, d: -0.4, res: -52.499999999999993 },
+        Lr { n: -2, d: 3.0, res: 0.074074074074074074 },
+        Lr { n:  0, d: 1e-15, res: 9.9999999999999892e14 },
+        Lr { n:  0, d: -1e-10, res: -1.0000000001e10 },
+        Lr { n:  0, d: 1e-5, res: 9.9998999999999992e4 },
+        Lr { n:  0, d: 0.3, res: 2.3333333333333335 },
+        Lr { n:  0, d: -0.4, res: -3.4999999999999999 },
+        Lr { n:  0, d: 3.0, res: -0.66666666666666667 },
+        Lr { n:  1, d: 1e-15, res: 34.538776394910685 },
+        Lr { n:  1, d: -1e-10, res: 23.025850929940457 },
+        Lr { n:  1, d: 1e-5, res: 11.512925464970228 },
+        Lr { n:  1, d: 0.3, res: 1.203972804325936 },
+        Lr { n:  1, d: -0.4, res: 0.91629073187415501 },
+        Lr { n:  1, d: 3.0, res: -1.0986122886681097 },
+        Lr { n:  2, d: 1e-15, res: 1.6449340668481909 },
+        Lr { n:  2, d: -1e-10, res: 1.6449340692508115 },
+        Lr { n:  2, d: 1e-5, res: 1.6448089369929265 },
+        Lr { n:  2, d: 0.3, res: 0.88937762428603876 },
+        Lr { n:  2, d: -0.4, res: 2.3190730363096612 },
+        Lr { n:  2, d: 3.0, res: -1.4367463668836809 },
+        Lr { n:  3, d: 1e-15, res: 1.2020569031595926 },
+        Lr { n:  3, d: -1e-10, res: 1.2020569033240877 },
+        Lr { n:  3, d: 1e-5, res: 1.2020404543873312 },
+        Lr { n:  3, d: 0.3, res: 0.78006393425766157 },
+        Lr { n:  3, d: -0.4, res: 1.8988811512408847 },
+        Lr { n:  3, d: 3.0, res: -1.6682833639665712 },
+        Lr { n:  4, d: 1e-15, res: 1.082323233711137 },
+        Lr { n:  4, d: -1e-10, res: 1.0823232338313439 },
+        Lr { n:  4, d: 1e-5, res: 1.0823112131642487 },
+        Lr { n:  4, d: 0.3, res: 0.73621724094913842 },
+        Lr { n:  4, d: -0.4, res: 1.598181449557007 },
+        Lr { n:  4, d: 3.0, res: -1.8131260153284912 },
+        Lr { n:  7, d: 1e-15, res: 1.0083492773819218 },
+        Lr { n:  7, d: -1e-10, res: 1.0083492774836571 },
+        Lr { n:  7, d: 1e-5, res: 1.0083391039522822 },
+        Lr { n:  7, d: 0.3, res: 0.70400232737741879 },
+        Lr { n:  7, d: -0.4, res: 1.4169338125684358 },
+        Lr { n:  7, d: 3.0, res: -1.9717014501148688 },
+    ];
+
+    let lc = vec![
+        Lc { n:  1, d: Complex::new(1e-10, 1e-10), res: Complex::new(22.679277339660484, -0.78539816339744831) },
+        Lc { n:  1, d: Complex::new(-1e-8, -2e-8), res: Complex::new(17.615961787735315, 2.0344439357957027) },
+        Lc { n:  1, d: Complex::new(0.2, 0.3), res: Complex::new(1.0201104142632773, -0.98279372324732903) },
+        Lc { n:  1, d: Complex::new(1.0, 1.0), res: Complex::new(-0.34657359027997265, -0.78539816339744831) },
+        Lc { n:  1, d: Complex::new(-1e-12, 0.0), res: Complex::new(27.631021115928548, -3.1415926535897932) },
+        Lc { n:  2, d: Complex::new(1e-10, 1e-10), res: Complex::new(1.6449340644017589, -2.2893879178580964e-9) },
+        Lc { n:  2, d: Complex::new(-1e-8, -2e-8), res: Complex::new(1.6449342123189687, 3.9266367179463755e-7) },
+        Lc { n:  2, d: Complex::new(0.2, 0.3), res: Complex::new(0.95005423624976059, -0.54133999117298744) },
+        Lc { n:  2, d: Complex::new(1.0, 1.0), res: Complex::new(-0.2056167583560283, -0.91596559417721902) },
+        Lc { n:  2, d: Complex::new(-1e-12, 0.0), res: Complex::new(1.6449340668768575, -3.1415926535882224e-12) },
+        Lc { n:  3, d: Complex::new(1e-10, 1e-10), res: Complex::new(1.2020569029951009, -1.6449340645947922e-10) },
+        Lc { n:  3, d: Complex::new(-1e-8, -2e-8), res: Complex::new(1.2020569196089319, 3.2898684526003512e-8) },
+        Lc { n:  3, d: Complex::new(0.2, 0.3), res: Complex::new(0.87715447783136016, -0.39395205868652941) },
+        Lc { n:  3, d: Complex::new(1.0, 1.0), res: Complex::new(-0.11269283467121196, -0.96894614625936938) },
+        Lc { n:  3, d: Complex::new(-1e-12, 0.0), res: Complex::new(1.2020569031612392, -1.5707963267933258e-24) },
+        Lc { n:  4, d: Complex::new(1e-10, 1e-10), res: Complex::new(1.0823232335909325, -1.2020569031153066e-10) },
+        Lc { n:  4, d: Complex::new(-1e-8, -2e-8), res: Complex::new(1.0823232457317072, 2.404113815176731e-8) },
+        Lc { n:  4, d: Complex::new(0.2, 0.3), res: Complex::new(0.83774398449951804, -0.33986549509999551) },
+        Lc { n:  4, d: Complex::new(1.0, 1.0), res: Complex::new(-0.05918955184357787, -0.98894455174110534) },
+        Lc { n:  4, d: Complex::new(-1e-12, 0.0), res: Complex::new(1.0823232337123402, -5.2359877559751344e-37) },
+        Lc { n:  7, d: Complex::new(1e-10, 1e-10), res: Complex::new(1.0083492772801885, -1.0173430619824907e-10) },
+        Lc { n:  7, d: Complex::new(-1e-8, -2e-8), res: Complex::new(1.0083492875553534, 2.0346861243605922e-8) },
+        Lc { n:  7, d: Complex::new(0.2, 0.3), res: Complex::new(0.80443403535688892, -0.3040403467451195) },
+        Lc { n:  7, d: Complex::new(1.0, 1.0), res: Complex::new(-7.7546392181471116e-3, -0.99955450789053991) },
+        Lc { n:  7, d: Complex::new(-1e-12, 0.0), res: Complex::new(1.0083492773829402, -4.3633231299727334e-75) },
+    ];
+
+    for l in lr.into_iter() {
+        assert_eq_float!(l.d.li_1m(l.n)/l.res, 1.0_f64, 1e-14_f64);
+        if l.n == 2 {
+            assert_eq_float!(l.d.li2_1m()/l.res, 1.0_f64, 1e-14_f64);
+        } else if l.n == 3 {
+            assert_eq_float!(l.d.li3_1m()/l.res, 1.0_f64, 1e-14_f64);
+        } else if l.n == 4 {
+            assert_eq_float!(l.d.li4_1m()/l.res, 1.0_f64, 1e-14_f64);
+        }
+    }
+
+    for l in lc.into_iter() {
+        let one = Complex::new(1.0_f64, 0.0_f64);
+        assert_eq_complex!(l.d.li_1m(l.n)/l.res, one, 1e-14_f64);
+        if l.n == 2 {
+            assert_eq_complex!(l.d.li2_1m()/l.res, one, 1e-14_f64);
+        } else if l.n == 3 {
+            assert_eq_complex!(l.d.li3_1m()/l.res, one, 1e-14_f64);
+        } else if l.n == 4 {
+            assert_eq_complex!(l.d.li4_1m()/l.res, one, 1e-14_f64);
+        }
+    }
+}
+
+#[test]
+fn test_consistency() {
+    for n in -5..=10 {
+        for i in -20..=20 {
+            let d = 0.1*(i as f64) + 0.05;
+            assert_eq_float!(d.li_1m(n), (1.0_f64 - d).li(n), 1e-13_f64);
+
+            for j in -20..=20 {
+                let d = Complex::new(d, 0.1*(j as f64) + 0.05);
+                let (a, b) = (d.li_1m(n), (1.0_f64 - d).li(n));
+                assert!((a - b).norm() < 1e-13_f64*(1.0_f64 + b.norm()));
+            }
+        }
+    }
+
+    for i in -20..=20 {
+        let d = Complex::new(0.1*(i as f64) + 0.05, 0.03_f64);
+        assert_eq_complex!(d.li2_1m(), (1.0_f64 - d).li2(), 1e-14_f64);
+        assert_eq_float!(d.re.li2_1m(), (1.0_f64 - d.re).li2(), 1e-14_f64);
+    }
+}
+
+#[test]
+fn test_limits() {
+    let z2 = 1.6449340668482264_f64;
+
+    assert!(0.0_f64.li2_1m() == z2);
+    assert!(Complex::new(0.0_f64, 0.0_f64).li2_1m() == Complex::new(z2, 0.0_f64));
+    assert!(0.0_f64.li_1m(5) == 1.0_f64.li(5));
+    assert!(std::f64::NAN.li2_1m().is_nan());
+    assert!(std::f64::NAN.li_1m(3).is_nan());
+    assert!(Complex::new(std::f64::NAN, 0.0_f64).li2_1m().is_nan());
+    assert!(Complex::new(std::f64::NAN, 0.0_f64).li_1m(3).is_nan());
+}
@@ -0,0 +1,58 @@
+use num::complex::Complex;
+use polylog::{Li, LiConst};
+mod common;
+use common::same;
+
+fn check_real<const N: i32>(x: &[f64]) {
+    for x in x.iter() {
+        assert!(same(x.polylog::<N>(), x.li(N)), "N = {}, x = {}", N, x);
+    }
+}
+
+fn check_complex<const N: i32>(z: &[Complex<f64>]) {
+    for z in z.iter() {
+        let a = z.polylog::<N>();
+        let b = z.li(N);
+        assert!(same(a.re, b.re) && same(a.im, b.im), "N = {}, z = {}", N, z);
+    }
+}
+
+#[test]
+fn test_identical_to_li() {
+    let mut x: Vec<f64> = (-100..=100).map(|i| 0.05*(i as f64) + 0.001).collect();
+    x.extend([0.0, 1.0, -1.0, 1e10, -1e10, std::f64::INFINITY, std::f64::NEG_INFINITY, std::f64::NAN].iter());
+
+    let mut z = vec![];
+    for i in -20..=20 {
+        for j in -20..=20 {
+            z.push(Complex::new(0.15*(i as f64) + 0.001, 0.15*(j as f64)));
+        }
+    }
+    z.extend([Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+              Complex::from_polar(1.0, 0.5), Complex::new(std::f64::INFINITY, 1.0),
+              Complex::new(std::f64::NAN, 1.0)].iter());
+
+    check_real::<-5>(&x);
+    check_real::<-1>(&x);
+    check_real::<0>(&x);
+    check_real::<1>(&x);
+    check_real::<2>(&x);
+    check_real::<3>(&x);
+    check_real::<4>(&x);
+    check_real::<5>(&x);
+    check_real::<6>(&x);
+    check_real::<7>(&x);
+    check_real::<20>(&x);
+
+    check_complex::<-5>(&z);
+    check_complex::<-1>(&z);
+    check_complex::<0>(&z);
+    check_complex::<1>(&z);
+    check_complex::<2>(&z);
+    check_complex::<3>(&z);
+    check_complex::<4>(&z);
+    check_complex::<5>(&z);
+    check_complex::<6>(&z);
+    check_complex::<7>(&z);
+    check_complex::<20>(&z);
+}
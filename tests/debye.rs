@@ -0,0 +1,80 @@
+use polylog::debye::debye;
+mod common;
+
+
+#[test]
+fn test_values() {
+    struct Deb { n: i32, x: f64, res: f64 }
+
+    let values = vec![
+        Deb { n:  1, x:    0.5, res: 0.88192715679060553_f64 },
+        Deb { n:  1, x:    3.0, res: 0.48043521957304284_f64 },
+        Deb { n:  1, x:   10.0, res: 0.16444346567994603_f64 },
+        Deb { n:  2, x:    1.0, res: 0.70787847562782928_f64 },
+        Deb { n:  3, x:    1.0, res: 0.67441556407781468_f64 },
+        Deb { n:  3, x:    2.5, res: 0.35413603481042394_f64 },
+        Deb { n:  3, x:    5.0, res: 0.11759741179993396_f64 },
+        Deb { n:  3, x:   20.0, res: 0.002435220067480548_f64 },
+        Deb { n:  3, x:  100.0, res: 1.9481818206800487e-5_f64 },
+        Deb { n:  4, x:    3.5, res: 0.19575088858983053_f64 },
+        Deb { n:  5, x:   0.01, res: 0.99583928570656968_f64 },
+        Deb { n: 10, x:    4.0, res: 0.10152269941617023_f64 },
+        Deb { n: 10, x:    8.0, res: 0.0062377172454635153_f64 },
+        Deb { n: 20, x:   12.0, res: 1.4720293947308444e-4_f64 },
+        Deb { n: 50, x:   30.0, res: 6.3127315858297244e-12_f64 },
+        Deb { n:  3, x:   -2.0, res: 1.9411284737276242_f64 },
+        Deb { n:  2, x: 1000.0, res: 4.8082276126383771e-6_f64 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(debye(v.n, v.x)/v.res, 1.0_f64, 1e-14_f64);
+    }
+}
+
+
+#[test]
+fn test_limits() {
+    assert!(debye(3, f64::NAN).is_nan());
+    assert!(debye(3, 0.0) == 1.0);
+    assert!(debye(3, f64::INFINITY) == 0.0);
+    assert!(debye(1, 1e-300) == 1.0);
+    assert!(debye(500, 1e3) == 0.0);
+    assert!(debye(500, 6e2) > 0.0);
+    assert!(debye(500, 6e2).is_finite());
+}
+
+
+#[test]
+fn test_continuity() {
+    // continuity at the boundaries between the different expansions
+    for &n in [1, 3, 5, 8, 9, 10, 20].iter() {
+        let nf = n as f64;
+        let bounds = [1.0 + 0.5*nf, 3.0, nf, 50.0 + 3.0*nf];
+        for &x in bounds.iter() {
+            let lo = debye(n, x*(1.0 - 1e-15));
+            let hi = debye(n, x*(1.0 + 1e-15));
+            // d ln(D_n(x))/d ln(x) > -n
+            assert_eq_float!(lo/hi, 1.0_f64, 4e-15*(nf + 1.0));
+        }
+    }
+}
+
+
+#[test]
+fn test_negative_argument() {
+    // D_n(-x) = D_n(x) + n x/(n+1)
+    for n in 1..10 {
+        for k in 1..40 {
+            let x = 0.5*(k as f64);
+            let nf = n as f64;
+            assert_eq_float!(debye(n, -x), debye(n, x) + nf*x/(nf + 1.0), 1e-14_f64);
+        }
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn test_non_positive_order() {
+    debye(0, 1.0);
+}
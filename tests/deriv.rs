@@ -0,0 +1,136 @@
+use num::complex::Complex;
+use polylog::{Li, LiDeriv};
+mod common;
+
+#[test]
+fn test_values() {
+    struct Dr { n: i32, k: u32, x: f64, res: f64 }
+    struct Dc { n: i32, k: u32, z: Complex<f64>, res: Complex<f64> }
+
+    let dr = vec![
+        Dr { n: -2, k: 1, x: 0.3, res: 9.537692628071636 },
+        Dr { n: -2, k: 3, x: -0.7, res: 0.6189521405407481 },
+        Dr { n:  0, k: 2, x: 0.5, res: 16.0 },
+        Dr { n:  1, k: 1, x: -0.25, res: 0.8 },
+        Dr { n:  1, k: 4, x: 0.8, res: 3.7500000000000033e3 },
+        Dr { n:  2, k: 1, x: 0.5, res: 1.3862943611198906 },
+        Dr { n:  2, k: 2, x: 2.0, res: -0.5 },
+        Dr { n:  2, k: 3, x: -3.0, res: 0.026299582305177083 },
+        Dr { n:  3, k: 1, x: 1e-3, res: 1.0002501111736511 },
+        Dr { n:  3, k: 5, x: 0.45, res: 7.3657899650092163 },
+        Dr { n:  4, k: 2, x: 0.95, res: 0.35131214702586216 },
+        Dr { n:  5, k: 3, x: -0.6, res: 0.015294278380692216 },
+        Dr { n:  6, k: 2, x: 0.85, res: 0.041830098831299555 },
+    ];
+
+    let dc = vec![
+        Dc { n: -2, k: 2, z: Complex::new(0.3, 0.1), res: Complex::new(47.445759999999994, 52.071679999999997) },
+        Dc { n:  0, k: 3, z: Complex::new(-0.5, 0.5), res: Complex::new(0.2688, 0.9216) },
+        Dc { n:  1, k: 2, z: Complex::new(1.0, 1.0), res: Complex::new(-1.0, 0.0) },
+        Dc { n:  2, k: 1, z: Complex::new(0.2, -0.3), res: Complex::new(1.0700175043472438, -0.18882709483199544) },
+        Dc { n:  2, k: 4, z: Complex::new(-2.0, 0.5), res: Complex::new(0.033945263845363242, 0.020819841443938104) },
+        Dc { n:  3, k: 2, z: Complex::new(0.6, 0.6), res: Complex::new(0.24759192199425061, 0.26258689238113591) },
+        Dc { n:  4, k: 3, z: Complex::new(1e-3, 1e-3), res: Complex::new(0.074167823888538707, 9.3942185184856447e-5) },
+        Dc { n:  5, k: 1, z: Complex::new(3.0, -1.0), res: Complex::new(1.1392046270365345, -0.23997707805885106) },
+        Dc { n:  7, k: 2, z: Complex::new(0.7, -0.5), res: Complex::new(0.017596235198490382, -2.063820936743955e-3) },
+    ];
+
+    for d in dr.into_iter() {
+        assert_eq_float!(d.x.li_deriv(d.n, d.k)/d.res, 1.0_f64, 1e-14_f64);
+    }
+
+    for d in dc.into_iter() {
+        assert_eq_complex!(d.z.li_deriv(d.n, d.k)/d.res, Complex::new(1.0_f64, 0.0_f64), 1e-14_f64);
+    }
+}
+
+#[test]
+fn test_large_order() {
+    // values for n >> k near |z| = 1, where all Li(n-j,z) ~ z
+    struct Dr { n: i32, k: u32, x: f64, res: f64 }
+    struct Dc { n: i32, k: u32, z: Complex<f64>, res: Complex<f64> }
+
+    let dr = vec![
+        Dr { n: 30, k: 4, x: 1.0, res: 2.0947197847360266e-17 },
+        Dr { n: 30, k: 4, x: 0.99, res: 2.0945875787517466e-17 },
+        Dr { n: 20, k: 3, x: 0.95, res: 1.7421180236930542e-9 },
+        Dr { n: 10, k: 3, x: 0.95, res: 0.00013166260095024154 },
+        Dr { n: 15, k: 3, x: 2.0, res: 4.7422893802995137e-7 },
+        Dr { n: 25, k: 6, x: -1.0, res: 2.2028901158841985e-17 },
+        Dr { n: 12, k: 3, x: -100.0, res: 9.0311743641240605e-7 },
+    ];
+
+    let dc = vec![
+        Dc { n: 15, k: 3, z: Complex::new(2.0, 0.0), res: Complex::new(4.7422893802995137e-7, -1.4539951201971652e-10) },
+        Dc { n: 25, k: 5, z: Complex::new(0.955336489125606, 0.29552020666133955), res: Complex::new(4.2851629307663086e-16, 8.7076824990355694e-18) },
+        Dc { n: 40, k: 6, z: Complex::new(-1.0, 0.0), res: Complex::new(5.3085166890465756e-29, 0.0) },
+        Dc { n: 20, k: 4, z: Complex::new(1.5, 0.01), res: Complex::new(2.3982558529705467e-11, 1.6549254916451904e-14) },
+        Dc { n: 18, k: 8, z: Complex::new(1.001, 0.0), res: Complex::new(9.6747256360768603e-12, -8.5259908202811339e-33) },
+        Dc { n: 50, k: 3, z: Complex::new(0.999, -0.001), res: Complex::new(8.3577523289946307e-24, -1.8934011899078475e-32) },
+    ];
+
+    for d in dr.into_iter() {
+        assert_eq_float!(d.x.li_deriv(d.n, d.k)/d.res, 1.0_f64, 1e-14_f64);
+    }
+
+    for d in dc.into_iter() {
+        assert_eq_complex!(d.z.li_deriv(d.n, d.k)/d.res, Complex::new(1.0_f64, 0.0_f64), 1e-14_f64);
+    }
+}
+
+#[test]
+fn test_recursion() {
+    // d/dz Li(n,z) = Li(n-1,z)/z
+    for n in -3..=8 {
+        for i in -20..=20 {
+            let x = 0.1*(i as f64) + 0.05;
+            assert_eq_float!(x.li_deriv(n, 1), x.li(n - 1)/x, 1e-14_f64);
+            assert_eq_float!(x.li_deriv(n, 0), x.li(n), 1e-14_f64);
+
+            for j in -20..=20 {
+                let z = Complex::new(x, 0.1*(j as f64) + 0.05);
+                assert_eq_complex!(z.li_deriv(n, 1), z.li(n - 1)/z, 1e-14_f64);
+            }
+        }
+    }
+
+    // z d^(k+1)/dz^(k+1) Li(n,z) + k d^k/dz^k Li(n,z) = d^k/dz^k Li(n-1,z)
+    for n in 1..=5 {
+        for k in 1..=4 {
+            for &(re, im) in [(0.3, 0.2), (-0.45, 0.1), (0.7, -0.4), (1.5, 1.0), (-2.0, 0.5)].iter() {
+                let z = Complex::new(re, im);
+                let lhs = z*z.li_deriv(n, k + 1) + (k as f64)*z.li_deriv(n, k);
+                let rhs = z.li_deriv(n - 1, k);
+                assert!((lhs - rhs).norm() < 1e-12_f64*(1.0_f64 + rhs.norm()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_special_values() {
+    // d^k/dz^k Li(n,z) at z = 0 is k!/k^n
+    for n in -3..=8 {
+        for k in 1..=6_u32 {
+            let fac = (1..=k).fold(1.0_f64, |p, i| p*(i as f64));
+            let res = fac/(k as f64).powi(n);
+            assert_eq_float!(0.0_f64.li_deriv(n, k), res, 1e-14_f64);
+            assert_eq_complex!(Complex::new(0.0_f64, 0.0_f64).li_deriv(n, k), Complex::new(res, 0.0_f64), 1e-14_f64);
+        }
+    }
+
+    // continuity at the boundary of the power series
+    for n in -2..=4 {
+        for k in 1..=4 {
+            for &r in [0.5_f64, 0.9_f64].iter() {
+                let x1 = r*(1.0 - 1e-15);
+                let x2 = r*(1.0 + 1e-15);
+                assert_eq_float!(x1.li_deriv(n, k), x2.li_deriv(n, k), 1e-12_f64);
+                assert_eq_float!((-x1).li_deriv(n, k), (-x2).li_deriv(n, k), 1e-12_f64);
+            }
+        }
+    }
+
+    assert!(std::f64::NAN.li_deriv(2, 3).is_nan());
+    assert!(Complex::new(std::f64::NAN, 0.0_f64).li_deriv(2, 3).is_nan());
+}
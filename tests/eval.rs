@@ -0,0 +1,66 @@
+use num::complex::Complex;
+use polylog::{Li, Polylog, PolylogEval};
+mod common;
+use common::same;
+
+#[test]
+fn test_identical_to_li_real() {
+    let mut x: Vec<f64> = (-400..=400).map(|i| 0.025*(i as f64) + 0.0001).collect();
+    x.extend([0.0, 1.0, -1.0, 0.99, 1.01, 1e10, -1e10, 1e-10, std::f64::INFINITY,
+              std::f64::NEG_INFINITY, std::f64::NAN].iter());
+
+    for n in -10..=40 {
+        let li = Polylog::new(n);
+        assert_eq!(li.order(), n);
+        for x in x.iter() {
+            assert!(same(li.eval(*x), x.li(n)), "n = {}, x = {}", n, x);
+        }
+    }
+}
+
+#[test]
+fn test_identical_to_li_complex() {
+    let mut z = vec![];
+    for i in -30..=30 {
+        for j in -30..=30 {
+            z.push(Complex::new(0.1*(i as f64) + 0.001, 0.1*(j as f64)));
+        }
+    }
+    for i in 0..100 {
+        z.push(Complex::from_polar(1.0, 0.0314*(i as f64) + 0.001));
+    }
+    z.extend([Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+              Complex::new(1e300, 1e300), Complex::new(std::f64::INFINITY, 0.0),
+              Complex::new(std::f64::NAN, 1.0)].iter());
+
+    for n in -10..=40 {
+        let li = Polylog::new(n);
+        for z in z.iter() {
+            let a = li.eval(*z);
+            let b = z.li(n);
+            assert!(same(a.re, b.re) && same(a.im, b.im), "n = {}, z = {}", n, z);
+        }
+    }
+}
+
+#[test]
+fn test_large_order() {
+    let li = Polylog::new(1000);
+    assert!(li.eval(0.5) == 0.5_f64.li(1000));
+    assert!(li.eval(2.0) == 2.0_f64.li(1000));
+    assert!(li.eval(Complex::new(0.9, 0.5)) == Complex::new(0.9, 0.5).li(1000));
+
+    // the size of the tabulated coefficients does not grow with n
+    for &n in [100_000_000, i32::MAX - 100].iter() {
+        let li = Polylog::new(n);
+        assert!(li.eval(0.5) == 0.5_f64.li(n));
+        assert!(li.eval(-1.0) == (-1.0_f64).li(n));
+        assert!(li.eval(2.0) == 2.0_f64.li(n));
+        assert!(li.eval(Complex::new(0.9, 0.5)) == Complex::new(0.9, 0.5).li(n));
+        assert!(li.eval(Complex::from_polar(1.0, 2.0)) == Complex::from_polar(1.0, 2.0).li(n));
+    }
+
+    let li = Polylog::new(i32::MAX);
+    assert_eq!(li.order(), i32::MAX);
+    assert!(li.eval(0.5) == 0.5_f64.li(i32::MAX));
+}
@@ -0,0 +1,182 @@
+use num::complex::Complex;
+use polylog::{Li, LiExp};
+use polylog::expansions::{li_asymptotic, li_asymptotic_coeffs, li_exp_unity, li_taylor, li_taylor_unity};
+mod common;
+
+fn eval(c: &[Complex<f64>], h: Complex<f64>) -> Complex<f64> {
+    c.iter().rev().fold(Complex::new(0.0, 0.0), |s, ck| s*h + ck)
+}
+
+#[test]
+fn test_taylor_values() {
+    struct T { n: i32, z0: Complex<f64>, res: [Complex<f64>; 4] }
+
+    // coefficients c_0, c_4, c_8, c_12
+    let values = vec![
+        T { n:  2, z0: Complex::new( 0.5, 0.5 ), res: [Complex::new(0.45398526915029558, 0.64376733288926875), Complex::new(-0.15342640972002735, 0.11873149673078164), Complex::new(0.14018615277338802, -0.123177279175849), Complex::new(-0.2404964073957014, 0.22072863672482292)] },
+        T { n:  3, z0: Complex::new( 0.1, 0.05), res: [Complex::new(0.10094597152662574, 0.051303375263566458), Complex::new(0.020162865513455066, 0.002857606996482149), Complex::new(0.0035342093444920631, 0.001288866486984246), Complex::new(0.0014106688545716025, 0.00089736740411748752)] },
+        T { n:  5, z0: Complex::new(-2.0, 1.0 ), res: [Complex::new(-1.9161285795601025, 0.90536868991718828), Complex::new(9.2784427502260128e-5, 7.2693496797190586e-5), Complex::new(-1.5249866814636871e-8, 7.1582319687039311e-8), Complex::new(-1.3294118634121118e-10, 2.2353200488000318e-11)] },
+        T { n:  2, z0: Complex::new( 3.0, 0.0 ), res: [Complex::new(2.3201804233130984, -3.4513922952232027), Complex::new(-0.0094347309241976997, 0.0096962736221907199), Complex::new(-0.00019395887695712866, 5.9853540877720493e-5), Complex::new(-4.9057702726861817e-6, 4.9262173561909871e-7)] },
+        T { n:  1, z0: Complex::new( 0.3, -0.4), res: [Complex::new(0.2153914580462271, -0.51914611424652297), Complex::new(-0.28668463989356116, -0.51762893456111473), Complex::new(-0.37150326228794793, 0.59358505881256364), Complex::new(1.1033628814209267, 0.059010984032561903)] },
+        T { n: -2, z0: Complex::new( 0.4, 0.7 ), res: [Complex::new(-0.92326480765316535, -1.3193568084673316), Complex::new(39.920264322734227, 7.4915883348874581), Complex::new(-177.16709017317817, -54.706934515998465), Complex::new(468.57109219506109, 275.56796968361128)] },
+        T { n:  4, z0: Complex::new( 0.9, 0.3 ), res: [Complex::new(0.95112450805406296, 0.34746278455749201), Complex::new(-0.021483989175456455, 0.035966068168550348), Complex::new(0.14613664302931184, 0.046527786944650799), Complex::new(1.0491573387686204, -2.1835719419737107)] },
+        T { n:  6, z0: Complex::new(-0.2, 0.1 ), res: [Complex::new(-0.19953414235287012, 0.09938952850885461), Complex::new(0.00018907370694074109, 2.1859335850180243e-5), Complex::new(1.5423493966053479e-6, 6.1198888175042673e-7), Complex::new(5.8143924669307771e-8, 4.6746391808632828e-8)] },
+    ];
+
+    for v in values.into_iter() {
+        let c = li_taylor(v.n, v.z0, 12);
+        assert_eq!(c.len(), 13);
+        for i in 0..4 {
+            assert_eq_complex!(c[4*i]/v.res[i], Complex::new(1.0_f64, 0.0_f64), 1e-13_f64);
+        }
+    }
+}
+
+#[test]
+fn test_taylor_consistency() {
+    for n in -4..=8 {
+        for &(re, im) in [(0.3, 0.2), (-0.7, 0.1), (0.45, -0.3), (0.55, 0.3), (1.5, 0.5), (-3.0, -2.0), (0.02, 0.01), (2.0, 0.0)].iter() {
+            let z0 = Complex::new(re, im);
+            let c = li_taylor(n, z0, 40);
+            assert_eq!(c.len(), 41);
+            assert!((c[0] - z0.li(n)).norm() < 1e-14_f64*(1.0_f64 + c[0].norm()));
+
+            // |h| = |1-z0|/4
+            let r = 0.25*(1.0 - z0).norm();
+            for &phi in [0.3_f64, 2.0, -1.5].iter() {
+                let h = Complex::from_polar(r, phi);
+                let z = z0 + h;
+                // avoid the branch cut of Li(n,z)
+                if z0.im == 0.0 && z.im > 0.0 { continue; }
+                let li = z.li(n);
+                assert!((eval(&c, h) - li).norm() < 1e-13_f64*(1.0_f64 + li.norm()));
+            }
+        }
+    }
+
+    // Li(n,h) = sum(k=1:Inf, h^k/k^n)
+    let c = li_taylor(3, Complex::new(0.0, 0.0), 4);
+    assert!(c[0] == Complex::new(0.0, 0.0));
+    assert!(c[2] == Complex::new(0.125, 0.0));
+
+    assert!(li_taylor(2, Complex::new(1.0, 0.0), 3).iter().all(|c| c.is_nan()));
+    assert!(li_taylor(2, Complex::new(std::f64::NAN, 0.0), 3).iter().all(|c| c.is_nan()));
+    assert_eq!(li_taylor(2, Complex::new(0.5, 0.0), 0).len(), 1);
+}
+
+#[test]
+fn test_taylor_unity() {
+    // Li(2,1+h) = zeta(2) + h (1 - ln(-h)) + ...
+    let s = li_taylor_unity(2, 3);
+    assert!(s.pole.is_empty());
+    assert_eq_float!(s.regular[0], 1.6449340668482264_f64, 1e-15_f64);
+    assert_eq_float!(s.regular[1], 1.0_f64, 1e-15_f64);
+    assert_eq_float!(s.log[0], 0.0_f64, 1e-15_f64);
+    assert_eq_float!(s.log[1], -1.0_f64, 1e-15_f64);
+
+    // Li(-1,1+h) = (1+h)/h^2
+    let s = li_taylor_unity(-1, 3);
+    assert_eq!(s.pole, vec![1.0, 1.0]);
+    assert_eq!(s.regular, vec![0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(s.log, vec![0.0, 0.0, 0.0, 0.0]);
+
+    for n in -4..=8 {
+        let s = li_taylor_unity(n, 40);
+        assert_eq!(s.regular.len(), 41);
+        assert_eq!(s.log.len(), 41);
+        assert_eq!(s.pole.len(), if n <= 0 { (1 - n) as usize } else { 0 });
+
+        for &(re, im) in [(0.1, 0.05), (-0.2, 0.1), (0.05, -0.2), (0.2, 0.0), (0.2, -0.0), (-0.15, 0.0)].iter() {
+            let h = Complex::new(re, im);
+            let li = (Complex::new(1.0, 0.0) + h).li(n);
+            assert!((s.eval(h) - li).norm() < 1e-13_f64*(1.0_f64 + li.norm()));
+        }
+    }
+
+    assert!(li_taylor_unity(2, 5).eval(Complex::new(0.0, 0.0)) == Complex::new(1.6449340668482264, 0.0));
+    assert!(li_taylor_unity(1, 5).eval(Complex::new(0.0, 0.0)).is_infinite());
+    assert!(li_taylor_unity(0, 5).eval(Complex::new(0.0, 0.0)).is_infinite());
+}
+
+#[test]
+fn test_exp_unity() {
+    // Li(2,e^mu) = zeta(2) + mu (1 - ln(-mu)) - mu^2/4 - mu^3/72 + ...
+    let s = li_exp_unity(2, 3);
+    assert!(s.pole.is_empty());
+    assert_eq!(s.regular, vec![1.6449340668482264, 1.0, -0.25, -1.0/72.0]);
+    assert_eq!(s.log, vec![0.0, -1.0, 0.0, 0.0]);
+
+    // Li(1,e^mu) = -ln(-mu) - mu/2 - mu^2/24 + ...
+    let s = li_exp_unity(1, 2);
+    assert_eq!(s.regular, vec![0.0, -0.5, -1.0/24.0]);
+    assert_eq!(s.log, vec![-1.0, 0.0, 0.0]);
+
+    // Li(-1,e^mu) = 1/mu^2 - 1/12 + ...
+    let s = li_exp_unity(-1, 2);
+    assert_eq!(s.pole, vec![0.0, 1.0]);
+    assert_eq!(s.regular, vec![-1.0/12.0, 0.0, 1.0/240.0]);
+
+    // coefficients beyond the range of the tabulated zeta(n) and 1/n!
+    assert_eq_float!(li_exp_unity(2, 201).regular[201]/1.1503507261130984e-164, 1.0_f64, 1e-14_f64);
+    assert_eq_float!(li_exp_unity(-3, 250).regular[250]/-5.8551525700566387e-196, 1.0_f64, 1e-14_f64);
+    assert_eq_float!(li_exp_unity(5, 172).regular[172]/1.1340772710431623e-145, 1.0_f64, 1e-14_f64);
+
+    // the logarithmic term lies beyond the requested order
+    let s = li_exp_unity(5, 2);
+    assert!(s.log.iter().all(|&c| c == 0.0));
+
+    for n in -5..=8 {
+        let s = li_exp_unity(n, 300);
+        assert!(s.regular.iter().all(|c| c.is_finite()));
+        assert_eq!(s.pole.len(), if n <= 0 { (1 - n) as usize } else { 0 });
+
+        for &(re, im) in [(0.1, 0.05), (-0.5, 1.0), (1.0, -2.0), (0.3, 0.0), (-0.3, 0.0), (-4.0, 3.0), (0.5, 4.5)].iter() {
+            let mu = Complex::new(re, im);
+            let li = mu.li_exp(n);
+            assert!((s.eval(mu) - li).norm() < 1e-13_f64*(1.0_f64 + li.norm()));
+        }
+    }
+}
+
+#[test]
+fn test_asymptotic() {
+    let pi2 = std::f64::consts::PI.powi(2);
+
+    // Li(3,z) = -ln(-z)^3/6 - pi^2/6 ln(-z) + O(1/z)
+    let a = li_asymptotic_coeffs(3);
+    assert_eq!(a.len(), 4);
+    assert_eq_float!(a[1], -pi2/6.0, 1e-15_f64);
+    assert!(a[0] == 0.0 && a[2] == 0.0);
+    assert_eq_float!(a[3], -1.0_f64/6.0, 1e-15_f64);
+
+    // Li(4,z) = -ln(-z)^4/24 - pi^2/12 ln(-z)^2 - 7 pi^4/360 + O(1/z)
+    let a = li_asymptotic_coeffs(4);
+    assert_eq_float!(a[0], -7.0*pi2*pi2/360.0, 1e-15_f64);
+    assert_eq_float!(a[2], -pi2/12.0, 1e-15_f64);
+
+    assert_eq!(li_asymptotic_coeffs(0), vec![-1.0]);
+    assert!(li_asymptotic_coeffs(-2).is_empty());
+
+    for n in -4..=8 {
+        for &(re, im) in [(10.0, 5.0), (-30.0, 1.0), (3.0, -4.0), (50.0, 0.0), (-20.0, 0.0), (2.0, 1.5), (0.0, -8.0)].iter() {
+            let z = Complex::new(re, im);
+            let li = z.li(n);
+
+            // error estimate bounds the truncation error
+            for order in 0..=6 {
+                let (res, err) = li_asymptotic(n, z, order);
+                assert!((res - li).norm() <= err + 1e-13_f64*(1.0_f64 + li.norm()));
+            }
+
+            // convergence for high orders
+            let (res, err) = li_asymptotic(n, z, 200);
+            assert!(err < 1e-15_f64*(1.0_f64 + li.norm()));
+            assert!((res - li).norm() < 1e-13_f64*(1.0_f64 + li.norm()));
+        }
+    }
+
+    let (res, err) = li_asymptotic(2, Complex::new(0.5, 0.5), 10);
+    assert!(res.is_finite() && err.is_infinite());
+    let (res, err) = li_asymptotic(2, Complex::new(std::f64::NAN, 0.0), 10);
+    assert!(res.is_nan() && err.is_nan());
+}
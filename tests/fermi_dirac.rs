@@ -0,0 +1,144 @@
+use polylog::Li;
+use polylog::fermi_dirac::{fermi_dirac, incomplete_fermi_dirac};
+mod common;
+
+
+#[test]
+fn test_values() {
+    struct Fd { j: f64, eta: f64, res: f64 }
+
+    let values = vec![
+        Fd { j:  -2.5, eta:      5.0, res: -0.03263403743618235_f64 },
+        Fd { j:  -2.5, eta:     50.0, res: -0.0007998677615579488_f64 },
+        Fd { j:  -1.5, eta:     -3.0, res: 0.04648369566542544_f64 },
+        Fd { j:  -1.5, eta:     10.0, res: 0.18092806859958435_f64 },
+        Fd { j:  -1.0, eta:      2.0, res: 0.8807970779778824_f64 },
+        Fd { j:  -0.5, eta:    -10.0, res: 4.5398472360805494e-05_f64 },
+        Fd { j:  -0.5, eta:      0.0, res: 0.6048986434216304_f64 },
+        Fd { j:  -0.5, eta:      3.0, res: 1.8534850886015177_f64 },
+        Fd { j:  -0.5, eta:     45.0, res: 7.567857081040255_f64 },
+        Fd { j:   0.0, eta:    -50.0, res: 1.9287498479639178e-22_f64 },
+        Fd { j:   0.0, eta:    800.0, res: 800.0_f64 },
+        Fd { j:   0.5, eta:     -1.5, res: 0.2073981870320298_f64 },
+        Fd { j:   0.5, eta:      1.0, res: 1.5756407761513003_f64 },
+        Fd { j:   0.5, eta:     20.0, res: 67.49151222165892_f64 },
+        Fd { j:   0.5, eta: 100000.0, res: 23788321.55163838_f64 },
+        Fd { j:   1.0, eta:   1000.0, res: 500001.64493406686_f64 },
+        Fd { j:   1.5, eta:     -0.5, res: 0.5526495259473541_f64 },
+        Fd { j:   1.5, eta:      5.0, res: 20.91446740276263_f64 },
+        Fd { j:   1.5, eta:    100.0, res: 30108.67168135487_f64 },
+        Fd { j:   2.0, eta:     -5.0, res: 0.006732283305485877_f64 },
+        Fd { j:   2.5, eta:     10.0, res: 311.3376418507202_f64 },
+        Fd { j:   3.0, eta:     30.0, res: 34492.1143957407_f64 },
+        Fd { j:   5.5, eta:     39.9, res: 14129299.189486843_f64 },
+        Fd { j:  10.5, eta:      2.0, res: 7.371278662736306_f64 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(fermi_dirac(v.j, v.eta), v.res, 1e-14_f64);
+    }
+}
+
+
+#[test]
+fn test_integer_order() {
+    // F_j(eta) = -Li(j+1,-e^eta)
+    for j in -5..10 {
+        for k in -40..40 {
+            let eta = 0.25*(k as f64);
+            assert_eq_float!(fermi_dirac(j as f64, eta), -(-eta.exp()).li(j + 1), 1e-13_f64);
+        }
+    }
+}
+
+
+#[test]
+fn test_limits() {
+    assert!(fermi_dirac(0.5, f64::NAN).is_nan());
+    assert!(fermi_dirac(0.5, f64::INFINITY) == f64::INFINITY);
+    assert!(fermi_dirac(0.5, f64::NEG_INFINITY) == 0.0);
+    assert!(fermi_dirac(-1.5, f64::INFINITY) == 0.0);
+    assert!(fermi_dirac(2.0, f64::INFINITY) == f64::INFINITY);
+    assert!(fermi_dirac(2.0, f64::NEG_INFINITY) == 0.0);
+    assert!(fermi_dirac(-1.0, f64::INFINITY) == 1.0);
+    assert_eq_float!(fermi_dirac(1.0, 1e100), 0.5e200_f64, 1e-15_f64);
+    assert_eq_float!(fermi_dirac(0.5, 1e100), 0.75225277806367504e150_f64, 1e-15_f64);
+}
+
+
+#[test]
+fn test_continuity() {
+    // continuity at the boundaries between the different expansions
+    for &j in [-2.5_f64, -1.5, -0.5, 0.5, 1.5, 2.5].iter() {
+        for &eta in [-1.0 + 2.0*j.min(0.0), 2.0, 40.0 - 4.0*j.min(0.0)].iter() {
+            let lo = fermi_dirac(j, eta - 1e-14);
+            let hi = fermi_dirac(j, eta + 1e-14);
+            assert_eq_float!(lo, hi, 1e-13_f64);
+        }
+    }
+}
+
+
+#[test]
+#[should_panic]
+fn test_non_half_integer_order() {
+    fermi_dirac(0.25, 1.0);
+}
+
+
+#[test]
+fn test_incomplete() {
+    struct Ifd { j: i32, eta: f64, b: f64, res: f64 }
+
+    let values = vec![
+        Ifd { j:   0, eta:     1.0, b:     2.0, res: 0.31326168751822283_f64 },
+        Ifd { j:   1, eta:     1.0, b:     2.0, res: 0.96517137143989785_f64 },
+        Ifd { j:   2, eta:    10.0, b:     1.0, res: 182.94940237148073_f64 },
+        Ifd { j:   3, eta:    -5.0, b:     2.0, res: 0.0057740230496396015_f64 },
+        Ifd { j:   3, eta:    50.0, b:    10.0, res: 262058.06164921928_f64 },
+        Ifd { j:   5, eta:    20.0, b:    30.0, res: 10.951643161095836_f64 },
+        Ifd { j:   2, eta:     0.0, b:   100.0, res: 1.8976107553682284e-40_f64 },
+        Ifd { j:  10, eta:     5.0, b:     5.0, res: 141.54260362598222_f64 },
+        Ifd { j:   1, eta:   500.0, b:   100.0, res: 120001.64493406685_f64 },
+        Ifd { j:   4, eta:     1.0, b:   700.0, res: 2.6966419890740672e-294_f64 },
+        Ifd { j:   0, eta:  -650.0, b:    50.0, res: 9.8596765437597709e-305_f64 },
+        Ifd { j: 100, eta:     0.0, b:   800.0, res: 9.1477257135916814e-216_f64 },
+        Ifd { j:   3, eta:    40.0, b:    39.0, res: 14444.042915204105_f64 },
+        Ifd { j:   7, eta:     0.5, b:    0.25, res: 1.6386962742027538_f64 },
+    ];
+
+    for v in values.into_iter() {
+        // relative comparison, values may be very small
+        assert_eq_float!(incomplete_fermi_dirac(v.j, v.eta, v.b)/v.res, 1.0_f64, 1e-14_f64);
+    }
+
+    // F_j(eta,0) = F_j(eta)
+    for j in 0..6 {
+        for k in -20..20 {
+            let eta = 2.5*(k as f64);
+            assert!(incomplete_fermi_dirac(j, eta, 0.0) == fermi_dirac(j as f64, eta));
+        }
+    }
+}
+
+
+#[test]
+fn test_incomplete_limits() {
+    assert!(incomplete_fermi_dirac(1, f64::NAN, 1.0).is_nan());
+    assert!(incomplete_fermi_dirac(1, 1.0, f64::NAN).is_nan());
+    assert!(incomplete_fermi_dirac(2, 1.0, -1.0).is_nan());
+    assert!(incomplete_fermi_dirac(0, 1.0, f64::NEG_INFINITY).is_nan());
+    assert!(incomplete_fermi_dirac(1, 1.0, f64::INFINITY) == 0.0);
+    assert!(incomplete_fermi_dirac(3, 0.0, 1e120) == 0.0);
+    assert!(incomplete_fermi_dirac(0, 1e10, 1.0) == 1e10 - 1.0);
+    // b^j/j! overflows, but F_j(eta,b) does not
+    assert_eq_float!(incomplete_fermi_dirac(40, 1e10 - 1000.0, 1e10)/6.2211837700202449e-83, 1.0_f64, 1e-13_f64);
+    assert!(incomplete_fermi_dirac(2, f64::INFINITY, 1.0) == f64::INFINITY);
+}
+
+
+#[test]
+#[should_panic]
+fn test_incomplete_negative_order() {
+    incomplete_fermi_dirac(-1, 1.0, 1.0);
+}
@@ -0,0 +1,154 @@
+use num::complex::Complex;
+use polylog::Li;
+use polylog::integrals::{antiderivative, integral, integral_complex};
+mod common;
+
+#[test]
+fn test_antiderivative() {
+    struct I { n: i32, k: i32, z: Complex<f64>, res: Complex<f64> }
+
+    let values = vec![
+        I { n:  2, k: 1, z: Complex::new( 0.5,  0.5 ), res: Complex::new(-0.10112366522764298, 0.079024159351939391) },
+        I { n:  1, k: 0, z: Complex::new( 0.3, -0.7 ), res: Complex::new(-0.25684966193934559, -0.15729223318291798) },
+        I { n:  3, k: 2, z: Complex::new(-1.5,  0.8 ), res: Complex::new(-0.6099015826901303, -1.7405590437989266) },
+        I { n:  0, k: 1, z: Complex::new( 0.4,  0.2 ), res: Complex::new(-0.0018546340629224685, 0.041750554396642201) },
+        I { n: -1, k: 0, z: Complex::new(-2.0,  1.0 ), res: Complex::new(0.45129254649702284, -0.22175055439664219) },
+        I { n: -2, k: 3, z: Complex::new( 0.3,  0.9 ), res: Complex::new(-0.16041707431981009, 0.052843426515639921) },
+        I { n:  5, k: 0, z: Complex::new( 2.0,  1.0 ), res: Complex::new(1.4969205751113678, 2.1416294620499178) },
+        I { n:  4, k: 4, z: Complex::new( 0.1,  0.05), res: Complex::new(-3.0665905387569198e-7, 1.1435972336005039e-7) },
+        I { n:  1, k: 5, z: Complex::new(-0.3,  0.2 ), res: Complex::new(6.1976246237408364e-5, -7.8593118056533639e-5) },
+        // large k
+        I { n:  2, k:  30, z: Complex::new( 0.5,  0.0 ), res: Complex::new(8.4248659095658083e-12, 0.0) },
+        I { n:  2, k:  40, z: Complex::new( 0.6,  0.0 ), res: Complex::new(1.3812475640333501e-11, 0.0) },
+        I { n:  2, k:  40, z: Complex::new(-0.6,  0.0 ), res: Complex::new(1.0110978029013444e-11, 0.0) },
+        I { n: -3, k:  20, z: Complex::new( 0.5,  0.0 ), res: Complex::new(4.6816130815166850e-7, 0.0) },
+        I { n:  1, k:  60, z: Complex::new( 0.0,  0.7 ), res: Complex::new(-3.5157475252551934e-12, -1.1316389421257678e-12) },
+        I { n:  0, k: 100, z: Complex::new( 0.9,  0.0 ), res: Complex::new(1.9506692524903471e-6, 0.0) },
+        I { n:  3, k: 200, z: Complex::new( 0.6,  0.7 ), res: Complex::new(-3.4313713761125289e-11, -3.8929832397531635e-10) },
+    ];
+
+    for v in values.into_iter() {
+        let res = antiderivative(v.n, v.k, v.z);
+        assert_eq_complex!(res/v.res, Complex::new(1.0_f64, 0.0_f64), 1e-13_f64);
+    }
+
+    // k = -1
+    for n in -3..=6 {
+        let z = Complex::new(0.7_f64, -1.3_f64);
+        assert!(antiderivative(n, -1, z) == z.li(n + 1));
+    }
+
+    // derivative of the antiderivative
+    let h = 1e-5_f64;
+    for n in -3..=6 {
+        for k in -4..=4 {
+            for &(re, im) in [(0.3, 0.4), (-1.2, 0.7), (2.5, -0.5), (0.6, -0.3)].iter() {
+                let z = Complex::new(re, im);
+                let d = (antiderivative(n, k, z + h) - antiderivative(n, k, z - h))/(2.0*h);
+                let f = z.powi(k)*z.li(n);
+                assert!((d - f).norm() < 1e-8_f64*(1.0_f64 + f.norm()));
+            }
+        }
+    }
+
+    // continuity at z = 1
+    for n in 1..=6 {
+        for k in -4..=4 {
+            let i1 = antiderivative(n, k, Complex::new(1.0, 0.0));
+            let i2 = antiderivative(n, k, Complex::new(1.0 - 1e-12, 0.0));
+            assert!((i1 - i2).norm() < 1e-9_f64);
+        }
+    }
+}
+
+#[test]
+fn test_integral() {
+    struct I { n: i32, k: i32, a: f64, b: f64, res: f64 }
+
+    let values = vec![
+        I { n:  2, k:  0, a:  0.0, b: 1.0, res: 0.64493406684822644 },
+        I { n:  1, k:  1, a:  0.0, b: 2.0, res: 2.0 },
+        I { n:  3, k:  2, a: -2.0, b: 3.0, res: 23.223111706883227 },
+        I { n:  2, k: -2, a:  0.5, b: 4.0, res: 2.5977831919743946 },
+        I { n:  0, k:  1, a: -3.0, b: 0.5, res: 2.9544415416798359 },
+        I { n: -2, k:  0, a:  1.5, b: 4.0, res: -10.680648358116944 },
+        I { n:  1, k: -3, a: -4.0, b: -0.5, res: 1.1979009127758132 },
+        I { n:  5, k:  7, a: -1.0, b: 1.0, res: 0.22303075884274804 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(integral(v.n, v.k, v.a, v.b), v.res, 1e-14_f64);
+        assert_eq_float!(integral(v.n, v.k, v.b, v.a), -v.res, 1e-14_f64);
+    }
+
+    assert!(integral(2, 3, 0.5, 0.5) == 0.0);
+    assert!(integral(2, -2, -1.0, 1.0).is_nan());
+    assert!(integral(0, 1, 0.0, 2.0).is_nan());
+    assert!(integral(-1, -1, 0.5, 1.0).is_nan());
+    assert!(integral(2, 1, std::f64::NAN, 1.0).is_nan());
+}
+
+#[test]
+fn test_integral_complex() {
+    struct I { n: i32, k: i32, a: Complex<f64>, b: Complex<f64>, res: Complex<f64> }
+
+    let values = vec![
+        I { n:  2, k:  0, a: Complex::new( 2.0, -1.0), b: Complex::new( 2.0,  1.0), res: Complex::new(0.0, 3.5579392975236371) },
+        I { n:  1, k:  0, a: Complex::new( 3.0,  1.0), b: Complex::new( 0.5, -1.0), res: Complex::new(0.73602012705754943, -1.1736360180220841) },
+        I { n:  3, k: -2, a: Complex::new(-1.0,  1.0), b: Complex::new(-2.0, -1.0), res: Complex::new(0.37122432514338271, 1.0699913407406024) },
+        I { n:  2, k: -3, a: Complex::new(-1.0, -1.0), b: Complex::new( 0.5,  2.0), res: Complex::new(-0.47286587851715477, 0.58664276667188881) },
+        I { n: -1, k:  1, a: Complex::new( 2.0,  1.0), b: Complex::new( 3.0, -2.0), res: Complex::new(2.6362943611198906, -6.8915926535897932) },
+        I { n:  0, k: -2, a: Complex::new(-2.0,  0.5), b: Complex::new(-1.0, -0.5), res: Complex::new(-0.22303543151705367, 0.29849893158617928) },
+        I { n:  4, k:  2, a: Complex::new( 1.5,  0.0), b: Complex::new( 0.5,  1.0), res: Complex::new(-1.4989486136746155, -0.396715455920587) },
+        I { n:  2, k:  1, a: Complex::new( 3.0,  0.0), b: Complex::new( 3.0, -2.0), res: Complex::new(-22.524108777274022, -1.4720565813005869) },
+        I { n:  2, k: -2, a: Complex::new(-1.0,  0.0), b: Complex::new(-1.0,  1.0), res: Complex::new(0.32370916363657891, -0.61265539533622563) },
+    ];
+
+    for v in values.into_iter() {
+        let res = integral_complex(v.n, v.k, v.a, v.b);
+        assert_eq_complex!(res, v.res, 1e-13_f64);
+        let res = integral_complex(v.n, v.k, v.b, v.a);
+        assert_eq_complex!(res, -v.res, 1e-13_f64);
+    }
+
+    // closed paths around the branch points
+    let z0 = Complex::new(0.0_f64, 0.0_f64);
+    let z1 = Complex::new(2.0_f64, -1.0_f64);
+    let z2 = Complex::new(2.0_f64, 1.0_f64);
+    let z3 = Complex::new(-2.0_f64, 1.0_f64);
+    let z4 = Complex::new(-2.0_f64, -1.0_f64);
+    for n in -3..=5 {
+        for k in -3..=3 {
+            let square = integral_complex(n, k, z1, z2) + integral_complex(n, k, z2, z3)
+                + integral_complex(n, k, z3, z4) + integral_complex(n, k, z4, z1);
+            let left = integral_complex(n, k, z3, z4) + integral_complex(n, k, z4, z0) + integral_complex(n, k, z0, z3);
+            let right = integral_complex(n, k, z1, z2) + integral_complex(n, k, z2, z0) + integral_complex(n, k, z0, z1);
+            if k <= -2 {
+                assert!(left.is_nan() && right.is_nan());
+                continue;
+            }
+            // the integrand is analytic at z = 0 for k >= -1
+            assert!((square - right).norm() < 1e-12_f64*(1.0_f64 + square.norm()));
+            assert!(left.norm() < 1e-12_f64);
+        }
+    }
+
+    // splitting a path at a point does not change the integral
+    for n in -2..=4 {
+        for k in -3..=3 {
+            for &(a, b) in [(Complex::new(-1.5_f64, 0.2_f64), Complex::new(3.0_f64, -0.9_f64)),
+                            (Complex::new(0.5_f64, 1.0_f64), Complex::new(3.0_f64, -1.0_f64))].iter() {
+                let i = integral_complex(n, k, a, b);
+                for &t in [0.1_f64, 0.5, 0.9].iter() {
+                    let c = a + t*(b - a);
+                    let s = integral_complex(n, k, a, c) + integral_complex(n, k, c, b);
+                    assert!((i - s).norm() < 1e-12_f64*(1.0_f64 + i.norm()));
+                }
+            }
+        }
+    }
+
+    assert!(integral_complex(0, 0, Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)).is_nan());
+    assert!(integral_complex(2, -2, Complex::new(-1.0, -1.0), Complex::new(1.0, 1.0)).is_nan());
+    assert!(integral_complex(2, 1, Complex::new(std::f64::NAN, 0.0), Complex::new(1.0, 1.0)).is_nan());
+}
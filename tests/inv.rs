@@ -0,0 +1,120 @@
+use num::complex::Complex;
+use polylog::{Li, LiInv};
+mod common;
+
+#[test]
+fn test_real_inverse() {
+    for n in 0..=8 {
+        for i in -100..=49 {
+            let x = if i < -50 { -(1.2_f64).powi(-50 - i) } else { 0.02*(i as f64) + 0.01 };
+            // Li(0,x) = x/(1-x) is ill-conditioned for x -> -Inf
+            if n == 0 && x < -10.0 { continue; }
+            let y = x.li(n);
+            assert_eq_float!(y.li_inv(n), x, 1e-13_f64);
+            assert_eq_float!(y.li_inv_from(n, 0.0), x, 1e-13_f64);
+            if n == 2 { assert_eq_float!(y.li2_inv(), x, 1e-13_f64); }
+            if n == 3 { assert_eq_float!(y.li3_inv(), x, 1e-13_f64); }
+        }
+    }
+}
+
+#[test]
+fn test_real_special_values() {
+    let z2 = 1.6449340668482264_f64;
+    let z3 = 1.2020569031595943_f64;
+
+    assert!(0.0_f64.li2_inv() == 0.0);
+    assert!(z2.li2_inv() == 1.0);
+    assert!(z3.li3_inv() == 1.0);
+    assert!((z2 + 1e-10).li2_inv().is_nan());
+    assert!((z3 + 1e-10).li3_inv().is_nan());
+    assert!(std::f64::NEG_INFINITY.li2_inv() == std::f64::NEG_INFINITY);
+    assert!(std::f64::NAN.li2_inv().is_nan());
+    assert!(1.0_f64.li_inv(-1).is_nan());
+    assert!((-2.0_f64).li_inv(0).is_nan());
+    assert_eq_float!((-0.5_f64).li_inv(0), -1.0_f64, 1e-15_f64);
+    assert_eq_float!(2.0_f64.li_inv(1), 1.0_f64 - (-2.0_f64).exp(), 1e-15_f64);
+
+    // Li2(1/2) = zeta(2)/2 - ln(2)^2/2
+    let l2 = std::f64::consts::LN_2;
+    assert_eq_float!((0.5_f64*z2 - 0.5_f64*l2*l2).li2_inv(), 0.5_f64, 1e-15_f64);
+
+    // Li2(-1) = -zeta(2)/2
+    assert_eq_float!((-0.5_f64*z2).li2_inv(), -1.0_f64, 1e-15_f64);
+
+    // small y
+    assert_eq_float!(1e-300_f64.li2_inv(), 1e-300_f64, 1e-15_f64);
+    assert_eq_float!((-1e-300_f64).li3_inv(), -1e-300_f64, 1e-15_f64);
+}
+
+#[test]
+fn test_complex_inverse() {
+    for n in -2..=6 {
+        for i in -10..=10 {
+            for j in -10..=10 {
+                let z = Complex::new(0.15*(i as f64) + 0.05, 0.15*(j as f64) + 0.05);
+                let w = z.li(n);
+                let zi = w.li_inv_from(n, z*Complex::new(1.01, 0.01));
+                assert!((zi.li(n) - w).norm() < 1e-13_f64*(1.0_f64 + w.norm()));
+
+                if n >= 2 && z.norm() < 1.0 {
+                    let zi = w.li_inv(n);
+                    assert!((zi - z).norm() < 1e-13_f64*(1.0_f64 + z.norm()));
+                }
+            }
+        }
+    }
+
+    // real values on the branch x <= 1
+    for &x in [-10.0_f64, -1.0, 0.3, 0.99].iter() {
+        let w = Complex::new(x.li(2), 0.0_f64);
+        assert_eq_complex!(w.li2_inv(), Complex::new(x, 0.0_f64), 1e-14_f64);
+        assert_eq_complex!(w.li3_inv().li(3), w, 1e-14_f64);
+    }
+
+    // Li(1,z) = -ln(1-z)
+    let w = Complex::new(1.0_f64, 2.0_f64);
+    assert_eq_complex!(w.li_inv(1), 1.0_f64 - (-w).exp(), 1e-15_f64);
+
+    // Li(-1,z) = z/(1-z)^2 = 2 for z = 1/2 and z = 2
+    let w = Complex::new(2.0_f64, 0.0_f64);
+    assert_eq_complex!(w.li_inv_from(-1, Complex::new(0.4_f64, 0.1_f64)), Complex::new(0.5_f64, 0.0_f64), 1e-15_f64);
+    assert_eq_complex!(w.li_inv_from(-1, Complex::new(2.5_f64, 0.1_f64)), Complex::new(2.0_f64, 0.0_f64), 1e-15_f64);
+
+    // large |w|
+    for &(re, im) in [(-100.0_f64, 3.0_f64), (30.0, 50.0), (2.0, -70.0)].iter() {
+        for n in 2..=4 {
+            let w = Complex::new(re, im).li(n);
+            assert!((w.li_inv(n).li(n) - w).norm() < 1e-13_f64*w.norm());
+        }
+    }
+
+    // large |w| and n < 0, start close to the pole at z = 1
+    for &(re, im) in [(1.0_f64, 1.0_f64), (-5.0, 0.0), (5.0, 0.0), (30.0, -50.0), (0.0, -3.0), (-1e6, 0.0)].iter() {
+        for &n in [-1, -2, -3, -5, -8].iter() {
+            let w = Complex::new(re, im);
+            assert!((w.li_inv(n).li(n) - w).norm() < 1e-14_f64*w.norm(), "n = {}, w = {}", n, w);
+        }
+    }
+
+    // Li(-1,z) = z/(1-z)^2 = -5 for z = (9 +- i sqrt(19))/10
+    let w = Complex::new(-5.0_f64, 0.0_f64);
+    assert_eq_complex!(w.li_inv(-1), Complex::new(0.9_f64, 0.1_f64*19.0_f64.sqrt()), 1e-15_f64);
+
+    // the damped iteration stagnates, which must not be reported as
+    // convergence
+    let zi = Complex::new(2.0_f64, 0.0_f64).li_inv(12);
+    assert!(zi.is_nan() || (zi.li(12) - 2.0_f64).norm() < 1e-14_f64);
+
+    // Re[Li(2,z)] <= pi^2/3 on the principal branch
+    assert!(Complex::new(5.0_f64, 3.0_f64).li2_inv().is_nan());
+
+    // |Im[Li(1,z)]| <= pi
+    let w = Complex::new(0.5_f64, 3.0_f64);
+    assert!((w.li_inv(1).li(1) - w).norm() < 1e-15_f64);
+    assert!(Complex::new(0.5_f64, 4.0_f64).li_inv(1).is_nan());
+    assert!(Complex::new(0.5_f64, -std::f64::consts::PI).li_inv(1).is_nan());
+    assert!(Complex::new(0.5_f64, 4.0_f64).li_inv_from(1, Complex::new(0.5_f64, 0.0_f64)).is_nan());
+
+    assert!(Complex::new(std::f64::NAN, 0.0_f64).li2_inv().is_nan());
+}
@@ -1,5 +1,5 @@
 use num::complex::Complex;
-use polylog::Li;
+use polylog::{Li, LiExp};
 mod common;
 
 #[test]
@@ -84,3 +84,191 @@ fn test_signed_zero() {
         assert!(Complex::new(nz64, nz64).li(n).im.is_sign_negative());
     }
 }
+
+
+#[test]
+fn test_unit_circle() {
+    struct Nt { n: i32, t: f64, re: f64, im: f64 }
+
+    let nt = vec![
+        Nt { n:   7, t:  1.0, re:  0.5365662572270589 , im:  0.8485811850405415 },
+        Nt { n:   7, t:  2.0, re: -0.4208311460208148 , im:  0.9033096360524407 },
+        Nt { n:   7, t:  3.0, re: -0.9828641491403347 , im:  0.13909912445533698},
+        Nt { n:   7, t: -2.5, re: -0.7988098136213356 , im: -0.591376869950928  },
+        Nt { n:  10, t:  1.0, re:  0.5398785706335893 , im:  0.8423605391686302 },
+        Nt { n:  10, t:  2.0, re: -0.4167691107596083 , im:  0.9085545120117295 },
+        Nt { n:  10, t:  3.0, re: -0.9890695240994615 , im:  0.14085366540017813},
+        Nt { n:  10, t: -2.5, re: -0.8008614410027136 , im: -0.5975510621325953 },
+        Nt { n:  20, t:  1.0, re:  0.5403019087150716 , im:  0.8414718520212728 },
+        Nt { n:  20, t:  3.0, re: -0.9899915811712536 , im:  0.141119741706196  },
+        Nt { n: 100, t:  2.0, re: -0.4161468365471424 , im:  0.9092974268256817 },
+        Nt { n: 100, t: -2.5, re: -0.8011436155469337 , im: -0.5984721441039565 },
+        Nt { n:  -2, t:  1.0, re:  0.0                , im: -1.9909690053692033 },
+        Nt { n:  -2, t:  3.0, re:  0.0                , im: -0.01781786725924798},
+        Nt { n:  -2, t: -2.5, re:  0.0                , im:  0.09223956779083126},
+        Nt { n:  -3, t:  1.0, re:  6.010502140375075  , im:  0.0                },
+        Nt { n:  -3, t:  2.0, re:  0.39488291831482564, im:  0.0                },
+        Nt { n:  -5, t:  1.0, re: -120.00639873506005 , im:  0.0                },
+        Nt { n:  -5, t: -2.5, re: -0.5328455419406811 , im:  0.0                },
+        Nt { n: -10, t:  2.0, re:  0.0                , im: -1771.4675811825666 },
+        Nt { n: -10, t: -2.5, re:  0.0                , im:  150.6059316892168  },
+        Nt { n: -50, t:  2.5, re:  0.0                , im: -1.5421777390731578e44 },
+        Nt { n: -51, t:  3.0, re:  2.4227542612896881e41, im:  0.0                },
+        Nt { n: -60, t:  3.0, re:  0.0                , im:  6.5163428088465792e52 },
+        Nt { n: -100, t: 2.5, re:  0.0                , im:  5.9987778449433916e117},
+        Nt { n: -150, t: 3.0, re:  0.0                , im: -5.1473466911529947e190},
+    ];
+
+    for v in nt.into_iter() {
+        let z = Complex::from_polar(1.0_f64, v.t);
+        let li = z.li(v.n);
+        assert_eq_complex!(li, Complex::new(v.re, v.im), 1e-14_f64);
+
+        // for n < 0 either the real or the imaginary part vanishes
+        if v.n < 0 {
+            assert!(if v.re == 0.0 { li.re == 0.0 } else { li.im == 0.0 });
+        }
+    }
+
+    // near t = pi the result is sensitive to the rounding of t
+    let nt_pi = vec![
+        Nt { n: -50, t:  3.14  , re:  0.0                , im: -6.9511115324334361e37 },
+        Nt { n: -51, t:  3.14  , re:  4.3655146884312967e40, im:  0.0                },
+        Nt { n: -60, t:  3.14  , re:  0.0                , im:  2.4290505778373971e50 },
+        Nt { n: -60, t: -3.14  , re:  0.0                , im: -2.4290505778373971e50 },
+        Nt { n: -100, t: 3.1415, re: 0.0                 , im:  3.4113864585555402e105},
+        Nt { n: -150, t: 3.14  , re:  0.0                , im: -7.4588791522456968e186},
+    ];
+
+    for v in nt_pi.into_iter() {
+        let li = Complex::from_polar(1.0_f64, v.t).li(v.n);
+        assert_eq_complex!(li, Complex::new(v.re, v.im), 1e-12_f64);
+        assert!(if v.re == 0.0 { li.re == 0.0 } else { li.im == 0.0 });
+    }
+
+    // Re[Li(n,e^(it))] = -(2 pi i)^n/(2 n!) B_n(t/(2 pi)) for even n
+    for &n in [8, 12, 16].iter() {
+        let bernoulli_poly = |x: f64| -> f64 {
+            (0..=n).map(|k| {
+                let binom = (1..=k).fold(1.0, |b, j| b*((n - k + j) as f64)/(j as f64));
+                binom*polylog::numbers::bernoulli_f64(k)*x.powi((n - k) as i32)
+            }).sum()
+        };
+        let fac = (1..=n).fold(1.0, |f, j| f*(j as f64));
+        let sgn = if (n/2) % 2 == 0 { -1.0 } else { 1.0 };
+        let tpi = 2.0*std::f64::consts::PI;
+
+        for &t in [0.5_f64, 1.0, 2.0, 3.0].iter() {
+            let re = sgn*tpi.powi(n as i32)/(2.0*fac)*bernoulli_poly(t/tpi);
+            assert_eq_float!(Complex::from_polar(1.0_f64, t).li(n as i32).re, re, 1e-14_f64);
+        }
+    }
+}
+
+#[test]
+fn test_li_exp() {
+    struct Lr { n: i32, mu: f64, res: f64 }
+    struct Lc { n: i32, mu: Complex<f64>, res: Complex<f64> }
+
+    let lr = vec![
+        Lr { n:  -5, mu: 1e-10, res: 1.2e62 },
+        Lr { n:  -2, mu: -0.1, res: 1.9999991673276953e3 },
+        Lr { n:  -1, mu: -1e-10, res: 1.0e20 },
+        Lr { n:   0, mu: 1e-10, res: -1.00000000005e10 },
+        Lr { n:   1, mu: -1e-10, res: 23.025850929990457 },
+        Lr { n:   1, mu: -50.0, res: 1.9287498479639178e-22 },
+        Lr { n:   1, mu: 2.0, res: -1.8545865421311409 },
+        Lr { n:   2, mu: 1e-10, res: 1.6449340692508115 },
+        Lr { n:   2, mu: -1e-10, res: 1.6449340644456413 },
+        Lr { n:   2, mu: 0.2, res: 2.1567105604360854 },
+        Lr { n:   3, mu: -1e-10, res: 1.2020569029951009 },
+        Lr { n:   3, mu: 2.0, res: 5.3841251137091404 },
+        Lr { n:   5, mu: 1e-12, res: 1.0369277551444522 },
+        Lr { n:  10, mu: -0.1, res: 0.90565021841185146 },
+        Lr { n:   2, mu: 1000.0, res: -4.999967101318663e5 },
+        Lr { n:   3, mu: 1000.0, res: -1.6666337679853297e8 },
+        Lr { n:  10, mu: 50.0, res: -2.3676672602105974e10 },
+    ];
+
+    let lc = vec![
+        Lc { n:  -5, mu: Complex::new(1e-10, 2e-10), res: Complex::new(8.9856e59, -3.3792e59) },
+        Lc { n:  -1, mu: Complex::new(-0.1, 0.2), res: Complex::new(-12.083458216914519, 15.99983293625394) },
+        Lc { n:   0, mu: Complex::new(1e-10, 2e-10), res: Complex::new(-2.0000000005e9, 4.0e9) },
+        Lc { n:   1, mu: Complex::new(1e-10, 2e-10), res: Complex::new(22.221131973673407, 2.0344439356957027) },
+        Lc { n:   1, mu: Complex::new(1000.0, 0.5), res: Complex::new(-1.0e3, 2.6415926535897932) },
+        Lc { n:   2, mu: Complex::new(1e-10, 2e-10), res: Complex::new(1.6449340687634508, 4.8476707883142516e-9) },
+        Lc { n:   2, mu: Complex::new(0.2, -3.0), res: Complex::new(-0.96591738864536642, -0.11289343851725009) },
+        Lc { n:   2, mu: Complex::new(1000.0, 0.5), res: Complex::new(-4.999981559281931e5, 2.6415926535897932e3) },
+        Lc { n:   3, mu: Complex::new(2.0, 1.0), res: Complex::new(0.035292662161846484, 6.0529526369273603) },
+        Lc { n:   3, mu: Complex::new(-700.0, 1.0), res: Complex::new(5.3272059717074144e-305, 8.2966317311648517e-305) },
+        Lc { n:   5, mu: Complex::new(0.3, 3.14159), res: Complex::new(-1.3007444565194076, 3.3376842298904985e-6) },
+        Lc { n:   7, mu: Complex::new(0.0, 1e-8), res: Complex::new(1.0083492773819228, 1.0173430619844491e-8) },
+        Lc { n:  10, mu: Complex::new(5.0, 10.0), res: Complex::new(-118.29458480551794, -72.112768546827915) },
+    ];
+
+    for l in lr.into_iter() {
+        assert_eq_float!(l.mu.li_exp(l.n)/l.res, 1.0_f64, 1e-14_f64);
+        assert_eq_float!(Complex::new(l.mu, 0.0).li_exp(l.n).re/l.res, 1.0_f64, 1e-14_f64);
+    }
+
+    for l in lc.into_iter() {
+        assert_eq_complex!(l.mu.li_exp(l.n)/l.res, Complex::new(1.0_f64, 0.0_f64), 1e-14_f64);
+        // periodicity in Im(mu)
+        if l.mu.norm() > 0.1 {
+            let mu = l.mu + Complex::new(0.0, 4.0*std::f64::consts::PI);
+            assert_eq_complex!(mu.li_exp(l.n)/l.res, Complex::new(1.0_f64, 0.0_f64), 1e-13_f64);
+        }
+    }
+}
+
+#[test]
+fn test_li_exp_consistency() {
+    for n in -4..=12 {
+        for i in -20..=20 {
+            for j in -15..=15 {
+                let mu = Complex::new(0.2*(i as f64) + 0.1, 0.2*(j as f64) + 0.1);
+                let (a, b) = (mu.li_exp(n), mu.exp().li(n));
+                assert!((a - b).norm() < 1e-13_f64*(1.0_f64 + b.norm()));
+                assert_eq_float!(mu.re.li_exp(n), mu.re.exp().li(n), 1e-13_f64);
+            }
+        }
+
+        // imaginary part on the branch cut z > 1
+        let mu = Complex::new(0.5_f64, 0.0_f64);
+        assert_eq_complex!(mu.li_exp(n), mu.exp().li(n), 1e-14_f64);
+    }
+}
+
+#[test]
+fn test_li_exp_limits() {
+    for n in -10..=10 {
+        assert!(std::f64::NAN.li_exp(n).is_nan());
+        assert!(Complex::new(std::f64::NAN, 0.0_f64).li_exp(n).is_nan());
+        assert!(Complex::new(0.0_f64, std::f64::INFINITY).li_exp(n).is_nan());
+        assert!(std::f64::NEG_INFINITY.li_exp(n) == 0.0_f64);
+        assert!(Complex::new(std::f64::NEG_INFINITY, 1.0_f64).li_exp(n) == Complex::new(0.0_f64, 0.0_f64));
+        assert!(0.0_f64.li_exp(n) == 1.0_f64.li(n));
+    }
+
+    assert!(std::f64::INFINITY.li_exp(2) == std::f64::NEG_INFINITY);
+    assert!(std::f64::INFINITY.li_exp(0) == -1.0_f64);
+    assert!(std::f64::INFINITY.li_exp(-3) == 0.0_f64);
+
+    // Li(n,e^mu) ~ (-n)!/(-mu)^(1-n) for mu -> 0 and n <= 0
+    for n in -10..=-1 {
+        for &mu in [1e-200_f64, -1e-200, 1e-320].iter() {
+            let sgn = if mu > 0.0 && n % 2 == 0 { -1.0_f64 } else { 1.0_f64 };
+            let li = Complex::new(mu, 0.0_f64).li_exp(n);
+            assert!(mu.li_exp(n) == sgn*std::f64::INFINITY, "n = {}, mu = {}", n, mu);
+            assert!(li == Complex::new(sgn*std::f64::INFINITY, 0.0_f64), "n = {}, mu = {}", n, mu);
+            assert!(!Complex::new(mu, mu).li_exp(n).is_nan(), "n = {}, mu = {}", n, mu);
+        }
+    }
+
+    let mu = Complex::new(1e-200_f64, 1e-200_f64);
+    assert_eq_complex!(mu.li_exp(0), Complex::new(-5e199_f64, 5e199_f64), 1e-15_f64);
+    assert!(Complex::new(1e-320_f64, 0.0_f64).li_exp(0) == Complex::new(std::f64::NEG_INFINITY, 0.0_f64));
+    assert!(mu.li_exp(-1).re == 0.0_f64 && mu.li_exp(-1).im == std::f64::NEG_INFINITY);
+    assert!(mu.li_exp(-2).re.is_infinite() && mu.li_exp(-2).im.is_infinite());
+    assert_eq_complex!(Complex::new(1e-100_f64, 1e-100_f64).li_exp(-2), Complex::new(5e299_f64, 5e299_f64), 1e-15_f64);
+}
@@ -0,0 +1,134 @@
+use num::{BigInt, BigRational, ToPrimitive};
+use polylog::numbers::*;
+
+
+fn rat(n: i64, d: i64) -> BigRational {
+    BigRational::new(BigInt::from(n), BigInt::from(d))
+}
+
+
+fn int(s: &str) -> BigInt {
+    s.parse::<BigInt>().unwrap()
+}
+
+
+#[test]
+fn test_bernoulli() {
+    assert_eq!(bernoulli(0), rat(1, 1));
+    assert_eq!(bernoulli(1), rat(-1, 2));
+    assert_eq!(bernoulli(2), rat(1, 6));
+    assert_eq!(bernoulli(3), rat(0, 1));
+    assert_eq!(bernoulli(4), rat(-1, 30));
+    assert_eq!(bernoulli(12), rat(-691, 2730));
+    assert_eq!(bernoulli(20), rat(-174611, 330));
+    assert_eq!(bernoulli(101), rat(0, 1));
+    assert_eq!(bernoulli(100), BigRational::new(
+        int("-94598037819122125295227433069493721872702841533066936133385696204311395415197247711"),
+        int("33330")));
+}
+
+
+#[test]
+fn test_bernoulli_f64() {
+    assert!(bernoulli_f64(0) == 1.0);
+    assert!(bernoulli_f64(1) == -0.5);
+    assert!(bernoulli_f64(2) == 1.0/6.0);
+    assert!(bernoulli_f64(3) == 0.0);
+    assert!(bernoulli_f64(257) == 0.0);
+    assert!(bernoulli_f64(258) == 1.3352784187354634e306);
+    assert!(bernoulli_f64(259) == 0.0);
+    assert!(bernoulli_f64(260) == f64::NEG_INFINITY);
+    assert!(bernoulli_f64(262) == f64::INFINITY);
+
+    for n in 0..=260 {
+        assert!(bernoulli_f64(n) == bernoulli(n).to_f64().unwrap());
+    }
+}
+
+
+#[test]
+fn test_euler() {
+    assert_eq!(euler(0), BigInt::from(1));
+    assert_eq!(euler(1), BigInt::from(0));
+    assert_eq!(euler(2), BigInt::from(-1));
+    assert_eq!(euler(4), BigInt::from(5));
+    assert_eq!(euler(6), BigInt::from(-61));
+    assert_eq!(euler(20), BigInt::from(370371188237525_i64));
+}
+
+
+#[test]
+fn test_euler_f64() {
+    assert!(euler_f64(0) == 1.0);
+    assert!(euler_f64(1) == 0.0);
+    assert!(euler_f64(186) == -3.245547458389247e306);
+    assert!(euler_f64(187) == 0.0);
+    assert!(euler_f64(188) == f64::INFINITY);
+    assert!(euler_f64(190) == f64::NEG_INFINITY);
+
+    for n in 0..=190 {
+        assert!(euler_f64(n) == BigRational::from_integer(euler(n)).to_f64().unwrap());
+    }
+}
+
+
+#[test]
+fn test_stirling1() {
+    assert_eq!(stirling1(0, 0), BigInt::from(1));
+    assert_eq!(stirling1(5, 0), BigInt::from(0));
+    assert_eq!(stirling1(3, 5), BigInt::from(0));
+    assert_eq!(stirling1(4, 4), BigInt::from(1));
+    assert_eq!(stirling1(4, 1), BigInt::from(-6));
+    assert_eq!(stirling1(10, 3), BigInt::from(-1172700));
+    assert_eq!(stirling1(30, 7), int("-11139316913434780466101123891200"));
+    assert!(stirling1_f64(10, 3) == -1172700.0);
+    assert!(stirling1_f64(300, 1) == f64::NEG_INFINITY);
+    assert!(stirling1_f64(301, 1) == f64::INFINITY);
+
+    // sum(k=0:n, s(n,k)) = 0 for n > 1
+    for n in 2..20 {
+        let sum: BigInt = (0..=n).map(|k| stirling1(n, k)).sum();
+        assert_eq!(sum, BigInt::from(0));
+    }
+}
+
+
+#[test]
+fn test_stirling2() {
+    assert_eq!(stirling2(0, 0), BigInt::from(1));
+    assert_eq!(stirling2(5, 0), BigInt::from(0));
+    assert_eq!(stirling2(3, 5), BigInt::from(0));
+    assert_eq!(stirling2(4, 2), BigInt::from(7));
+    assert_eq!(stirling2(10, 3), BigInt::from(9330));
+    assert!(stirling2_f64(10, 3) == 9330.0);
+    assert!(stirling2_f64(1000, 500) == f64::INFINITY);
+
+    // S(n,2) = 2^(n-1) - 1
+    for n in 1..60 {
+        assert_eq!(stirling2(n, 2), (BigInt::from(1) << (n as usize - 1)) - 1);
+    }
+}
+
+
+#[test]
+fn test_eulerian() {
+    assert_eq!(eulerian(0, 0), BigInt::from(1));
+    assert_eq!(eulerian(0, 1), BigInt::from(0));
+    assert_eq!(eulerian(1, 0), BigInt::from(1));
+    assert_eq!(eulerian(4, 0), BigInt::from(1));
+    assert_eq!(eulerian(4, 1), BigInt::from(11));
+    assert_eq!(eulerian(4, 2), BigInt::from(11));
+    assert_eq!(eulerian(4, 3), BigInt::from(1));
+    assert_eq!(eulerian(4, 4), BigInt::from(0));
+    assert_eq!(eulerian(7, 3), BigInt::from(2416));
+    assert!(eulerian_f64(7, 3) == 2416.0);
+    assert!(eulerian_f64(200, 100) == f64::INFINITY);
+
+    // sum(k=0:(n-1), A(n,k)) = n!
+    let mut fac = BigInt::from(1);
+    for n in 1..30 {
+        fac *= BigInt::from(n);
+        let sum: BigInt = (0..n).map(|k| eulerian(n, k)).sum();
+        assert_eq!(sum, fac);
+    }
+}
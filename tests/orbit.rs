@@ -0,0 +1,59 @@
+use num::complex::Complex;
+use polylog::{Li2, Li2Orbit};
+mod common;
+
+fn orbit(z: Complex<f64>) -> [Complex<f64>; 6] {
+    let one = Complex::new(1.0, 0.0);
+    [z, one - z, one/z, one/(one - z), z/(z - one), (z - one)/z]
+}
+
+#[test]
+fn test_li2_orbit() {
+    let mut zs = vec![];
+
+    for i in -40..=40 {
+        for j in -40..=40 {
+            zs.push(Complex::new(0.1*(i as f64) + 0.001, 0.1*(j as f64)));
+        }
+    }
+
+    // arguments close to 0, 1 and infinity
+    for &(re, im) in [(1e-10, 1e-10), (-1e-12, 3e-12), (1.0 + 1e-10, -1e-10),
+                      (1.0 - 1e-9, 1e-12), (1e10, 1e10), (-3e12, -1e11),
+                      (0.5, 0.5), (0.5, -0.5), (1.0, 1.0), (0.5, 0.8660254037844386)].iter() {
+        zs.push(Complex::new(re, im));
+    }
+
+    for z in zs.into_iter() {
+        let li = z.li2_orbit();
+        for (l, w) in li.iter().zip(orbit(z).iter()) {
+            let expected = w.li2();
+            assert!((l - expected).norm() < 1e-14_f64*(1.0_f64 + expected.norm()),
+                    "z = {}, w = {}: {} != {}", z, w, l, expected);
+        }
+    }
+}
+
+#[test]
+fn test_li2_orbit_real() {
+    for i in -40..=40 {
+        let x = 0.1*(i as f64) + 0.001;
+        let li = x.li2_orbit();
+        let z = Complex::new(x, 0.0);
+        let lz = z.li2_orbit();
+        let ws = [x, 1.0 - x, 1.0/x, 1.0/(1.0 - x), x/(x - 1.0), (x - 1.0)/x];
+
+        for k in 0..6 {
+            assert!(li[k] == ws[k].li2());
+            assert!(lz[k] == orbit(z)[k].li2());
+            assert_eq_float!(li[k], lz[k].re, 1e-14_f64);
+        }
+    }
+
+    // Li2(-1) and Li2(2) on the branch cut
+    let li = Complex::new(2.0, 0.0).li2_orbit();
+    assert_eq_complex!(li[0], Complex::new(2.4674011002723397_f64, -2.1775860903036021_f64), 1e-15_f64);
+    assert_eq_complex!(li[3], Complex::new(-0.8224670334241132_f64, 0.0_f64), 1e-15_f64);
+
+    assert!(Complex::new(std::f64::NAN, 1.0).li2_orbit().iter().all(|l| l.is_nan()));
+}
@@ -0,0 +1,176 @@
+use num::complex::Complex;
+use polylog::{Li, LiOrderDeriv};
+mod common;
+
+#[test]
+fn test_values() {
+    struct Dr { n: i32, x: f64, res: [f64; 4] }
+    struct Dc { n: i32, z: Complex<f64>, res: [Complex<f64>; 4] }
+
+    let dr = vec![
+        Dr { n:  1, x: 0.5 , res: [0.69314718055994531, -0.17289680030426476, 0.17332945971876872, -0.19689264179956303] },
+        Dr { n:  1, x: 0.9 , res: [2.3025850929940457, -2.0534617874984131, 3.7124712299541029, -7.8684392421988120] },
+        Dr { n:  2, x: 0.9 , res: [1.2997147230049587, -0.43451335027825501, 0.57762148330697493, -0.93316603950773469] },
+        Dr { n:  6, x: 0.99, res: [1.0069760494289159, -0.012568436236856663, 0.0097044666801415787, -0.0080016407107675629] },
+        Dr { n:  2, x: 1.0 , res: [1.6449340668482264, -0.93754825431584375, 1.9892802342989010, -6.0001458028430449] },
+        Dr { n:  0, x: 1.0 , res: [-0.5, -0.91893853320467274, -2.0063564559085849, -6.0047111668622544] },
+        Dr { n: -3, x: 1.0 , res: [0.0083333333333333333, 0.0053785763577743011, -0.014257862045242975, -0.030953604218675607] },
+        Dr { n:  1, x: 1.2 , res: [1.6094379124341004, 3.3822776405629215, -9.9576173168707870, 13.155396856054185] },
+    ];
+
+    // near the negative real axis
+    let dr_neg = vec![
+        Dr { n:  1, x: -1.0, res: [-0.69314718055994531, -0.15986890374243097, 0.065372592558898599, -0.0094139502324930897] },
+        Dr { n:  2, x: -1.0, res: [-0.82246703342411322, -0.10131657816350450, 0.050375577025452467, -0.017796701498469381] },
+        Dr { n:  1, x: -3.0, res: [-1.3862943611198906, -0.61245460393786013, 0.092055339436295178, 0.10560742079694196] },
+        Dr { n:  2, x: -3.0, res: [-1.9393754207667090, -0.48429837011457586, 0.14923231556736981, 0.015883903967078206] },
+    ];
+
+    let dc = vec![
+        Dc { n: -3, z: Complex::new(-0.95, 0.1), res: [Complex::new(0.12611228662816509, -0.0012079960762224555), Complex::new(-0.023872045246305501, -0.025339670899610345), Complex::new(-0.26297004945655464, 0.020958140500101385), Complex::new(0.10980278723533132, 0.032003229597854199)] },
+        Dc { n: -2, z: Complex::new(0.9, -0.2), res: [Complex::new(-138.0, 76.0), Complex::new(235.83085963277218, -348.42580002233894), Complex::new(-192.17992854735862, 1145.2512963694696), Complex::new(-769.61801767571106, -3220.6577289191466)] },
+        Dc { n: -2, z: Complex::new(1.5, 0.5), res: [Complex::new(3.0, 11.0), Complex::new(23.285729746400324, -24.103308006965481), Complex::new(-94.898563730464395, -18.398301007719828), Complex::new(114.76524480194785, 250.13581479146005)] },
+        Dc { n:  0, z: Complex::new(0.8, 0.3), res: [Complex::new(0.53846153846153846, 2.3076923076923077), Complex::new(1.4454568509520151, -2.0856299243740906), Complex::new(-3.4654921714399988, 1.7843807669178527), Complex::new(6.9172985458736159, -0.11923424093953753)] },
+        Dc { n:  1, z: Complex::new(1.2, 0.0), res: [Complex::new(1.6094379124341004, -3.1415926535897932), Complex::new(3.3822776405629215, 3.5335619130928646), Complex::new(-9.9576173168707870, 1.1932765082458899), Complex::new(13.155396856054185, -20.519857870818249)] },
+        Dc { n:  3, z: Complex::new(1.0, 1.0), res: [Complex::new(0.87115888341093802, 1.2670834418889240), Complex::new(0.14553331225724960, -0.16573118317804978), Complex::new(-0.15656138727159689, 0.074255000382860419), Complex::new(0.15290713507025691, 0.017134394986828909)] },
+        Dc { n:  4, z: Complex::new(0.3, 0.4), res: [Complex::new(0.29398330480553136, 0.41535593703709470), Complex::new(0.0048881781875916104, -0.010721187535451927), Complex::new(-0.0042091721797155539, 0.0074629540167402401), Complex::new(0.0038601736925872068, -0.0051276058329991867)] },
+        Dc { n:  5, z: Complex::new(2.0, 0.5), res: [Complex::new(2.1368248522564769, 0.62116490937930429), Complex::new(-0.091158880544344905, -0.11773044275889757), Complex::new(0.045896958817110252, 0.12124665336942292), Complex::new(0.0079271864788536308, -0.12406221450825103)] },
+        Dc { n:  1, z: Complex::new(90.0, 30.0), res: [Complex::new(-4.5424452606293835, 2.8164751085496433), Complex::new(-4.4119171958877356, 5.8495428323732502), Complex::new(-0.38187683210411567, 7.6387786113664631), Complex::new(5.6422589830569046, 3.7695155329469117)] },
+        Dc { n:  2, z: Complex::new(100.0, -5.0), res: [Complex::new(-7.4853791397104407, -14.241862887809185), Complex::new(0.054775272694589424, -15.769474515046468), Complex::new(8.8754212985801635, -8.3394317361548915), Complex::new(8.4702186846708740, 5.2155616580172976)] },
+        Dc { n:  4, z: Complex::new(-60.0, -5.0), res: [Complex::new(-27.402507146774693, -1.5149035764148886), Complex::new(-9.2059923170827394, -0.74382906124266663), Complex::new(0.42458390085470269, -0.084254074457294862), Complex::new(1.2607663234064449, 0.12545805573082104)] },
+        Dc { n:  6, z: Complex::new(200.0, -40.0), res: [Complex::new(89.947867006467220, -121.37879442956663), Complex::new(47.781225839792270, -5.3672969352030357), Complex::new(-2.2933677073840550, 18.048379360696289), Complex::new(-12.066718596893767, -2.9906740428653665)] },
+    ];
+
+    // near the negative real axis
+    let dc_neg = vec![
+        Dc { n:  0, z: Complex::new(-2.0, 1.0), res: [Complex::new(-0.7, 0.1), Complex::new(-0.48909914146504824, 0.19699994724202633), Complex::new(0.039181029039705127, 0.081801765484325551), Complex::new(0.14035170194223174, -0.10161178947866015)] },
+        Dc { n:  1, z: Complex::new(-1.5, 0.5), res: [Complex::new(-0.93590108845079571, 0.19739555984988076), Complex::new(-0.27586181880682632, 0.11777936599882102), Complex::new(0.095531180361595620, -0.016413717686553765), Complex::new(0.0048486899242949988, -0.025708084204405901)] },
+        Dc { n:  1, z: Complex::new(-0.8, -0.2), res: [Complex::new(-0.59392171119802618, -0.11065722117389565), Complex::new(-0.11427492185113668, -0.043241894617634880), Complex::new(0.053060369220909790, 0.014340833100791331), Complex::new(-0.014274723001322428, 0.0018211802434701646)] },
+        Dc { n:  3, z: Complex::new(-0.9, 0.4), res: [Complex::new(-0.82946021081185968, 0.33353558789748244), Complex::new(-0.044831725005403700, 0.038684575440063663), Complex::new(0.026855098093908377, -0.019899618284829608), Complex::new(-0.014338023867968578, 0.0076343971113874822)] },
+    ];
+
+    for d in dr.into_iter() {
+        let res = d.x.li_order_derivs(d.n, 3);
+        for (r, e) in res.iter().zip(d.res.iter()) {
+            assert_eq_float!(r/e, 1.0_f64, 1e-14_f64);
+        }
+    }
+
+    for d in dr_neg.into_iter() {
+        let res = d.x.li_order_derivs(d.n, 3);
+        for (r, e) in res.iter().zip(d.res.iter()) {
+            assert_eq_float!(r/e, 1.0_f64, 1e-11_f64);
+        }
+    }
+
+    for d in dc.into_iter() {
+        let res = d.z.li_order_derivs(d.n, 3);
+        for (r, e) in res.iter().zip(d.res.iter()) {
+            assert_eq_complex!(r/e, Complex::new(1.0_f64, 0.0_f64), 1e-13_f64);
+        }
+    }
+
+    for d in dc_neg.into_iter() {
+        let res = d.z.li_order_derivs(d.n, 3);
+        for (r, e) in res.iter().zip(d.res.iter()) {
+            assert_eq_complex!(r/e, Complex::new(1.0_f64, 0.0_f64), 1e-11_f64);
+        }
+    }
+}
+
+#[test]
+fn test_consistency() {
+    // the zeroth derivative is Li(n,z)
+    for n in -4..=8 {
+        for i in -10..=10 {
+            let x = 0.2*(i as f64) + 0.05;
+            assert_eq_float!(x.li_order_derivs(n, 0)[0], x.li(n), 1e-13_f64);
+
+            for j in -10..=10 {
+                let z = Complex::new(x, 0.2*(j as f64) + 0.05);
+                let d = z.li_order_derivs(n, 2);
+                let li = z.li(n);
+                assert_eq!(d.len(), 3);
+                assert!((d[0] - li).norm() < 1e-13_f64*(1.0_f64 + li.norm()));
+            }
+        }
+    }
+
+    // Li(s,z) + Li(s,-z) = 2^(1-s) Li(s,z^2)
+    for n in -2..=5 {
+        for &(re, im) in [(0.3, 0.2), (0.6, -0.3), (0.9, 0.1), (1.2, 0.8)].iter() {
+            let z = Complex::new(re, im);
+            let dp = z.li_order_derivs(n, 3);
+            let dm = (-z).li_order_derivs(n, 3);
+            let ds = (z*z).li_order_derivs(n, 3);
+            let l2 = -std::f64::consts::LN_2;
+            let p2 = 2.0_f64.powi(1 - n);
+
+            for i in 0..4 {
+                let binom = |k: i32| (0..k).fold(1.0_f64, |b, j| b*((i - j) as f64)/((j + 1) as f64));
+                let rhs = (0..=i).fold(Complex::new(0.0_f64, 0.0_f64), |sum, k| {
+                    sum + binom(k)*p2*l2.powi(k)*ds[(i - k) as usize]
+                });
+                let lhs = dp[i as usize] + dm[i as usize];
+                assert!((lhs - rhs).norm() < 1e-12_f64*(1.0_f64 + rhs.norm()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_special_values() {
+    let pi = std::f64::consts::PI;
+
+    // zeta(0) = -1/2, zeta'(0) = -ln(2 pi)/2
+    let d = 1.0_f64.li_order_derivs(0, 1);
+    assert_eq_float!(d[0], -0.5_f64, 1e-15_f64);
+    assert_eq_float!(d[1], -0.5_f64*(2.0_f64*pi).ln(), 1e-15_f64);
+
+    // zeta'(-2) = -zeta(3)/(4 pi^2)
+    let d = 1.0_f64.li_order_derivs(-2, 1);
+    assert_eq_float!(d[1], -1.2020569031595943_f64/(4.0_f64*pi*pi), 1e-15_f64);
+
+    // eta'(1) = gamma ln(2) - ln(2)^2/2
+    let l2 = std::f64::consts::LN_2;
+    let d = (-1.0_f64).li_order_derivs(1, 1);
+    assert_eq_float!(d[1], -(0.57721566490153286_f64*l2 - 0.5_f64*l2*l2), 1e-15_f64);
+
+    // continuity at the boundaries of the series and the duplication formula
+    for n in -2..=4 {
+        for &(r, phi) in [(0.75_f64, 0.3_f64), (0.75, 2.5), (0.9, 2.0/3.0*pi), (1.5, 2.0/3.0*pi)].iter() {
+            let z1 = Complex::from_polar(r*(1.0 - 1e-15), phi*(1.0 - 1e-15));
+            let z2 = Complex::from_polar(r*(1.0 + 1e-15), phi*(1.0 + 1e-15));
+            let d1 = z1.li_order_derivs(n, 3);
+            let d2 = z2.li_order_derivs(n, 3);
+            for i in 0..4 {
+                assert!((d1[i] - d2[i]).norm() < 1e-12_f64*(1.0_f64 + d1[i].norm()));
+            }
+        }
+    }
+
+    assert!(1.0_f64.li_order_derivs(1, 2).iter().all(|d| d.is_infinite()));
+    assert!(std::f64::NAN.li_order_derivs(2, 3).iter().all(|d| d.is_nan()));
+    assert!(Complex::new(std::f64::NAN, 0.0_f64).li_order_derivs(2, 3).iter().all(|d| d.is_nan()));
+
+    // continuity at the boundary of the expansion around z = 1
+    for n in -2..=6 {
+        for &phi in [0.1_f64, 1.0, 2.0, 3.0].iter() {
+            let z1 = Complex::from_polar(4.0*(1.0 - 1e-15), phi).exp();
+            let z2 = Complex::from_polar(4.0*(1.0 + 1e-15), phi).exp();
+            let d1 = z1.li_order_derivs(n, 3);
+            let d2 = z2.li_order_derivs(n, 3);
+            for i in 0..4 {
+                assert!((d1[i] - d2[i]).norm() < 1e-12_f64*(1.0_f64 + d1[i].norm()));
+            }
+        }
+    }
+
+    // the real part is continuous across the branch cut
+    let d = 100.0_f64.li_order_derivs(2, 3);
+    let dc = Complex::new(100.0_f64, 1e-300_f64).li_order_derivs(2, 3);
+    assert_eq_float!(d[0], 100.0_f64.li(2), 1e-14_f64);
+    for i in 0..4 {
+        assert_eq_float!(d[i], dc[i].re, 1e-12_f64);
+    }
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "rayon")]
+
+use ndarray::{Array1, Array2, Array3};
+use num::complex::Complex;
+use polylog::{Li, Li0, Li1, Li2, Li3, Li4, Li5, Li6};
+use polylog::par::*;
+mod common;
+use common::same;
+
+fn grid_real() -> Vec<f64> {
+    let mut x: Vec<f64> = (-10000..=10000).map(|i| 0.001*(i as f64) + 0.0001).collect();
+    x.extend([0.0, 1.0, -1.0, 1e10, -1e10, 1e-10, std::f64::NAN].iter());
+    x
+}
+
+fn grid_complex() -> Vec<Complex<f64>> {
+    let mut z = vec![];
+    for i in -40..=40 {
+        for j in -40..=40 {
+            z.push(Complex::new(0.1*(i as f64) + 0.001, 0.1*(j as f64)));
+        }
+    }
+    z.extend([Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+              Complex::new(std::f64::NAN, 1.0)].iter());
+    z
+}
+
+/// equality including NaN
+fn same_complex(a: Complex<f64>, b: Complex<f64>) -> bool {
+    same(a.re, b.re) && same(a.im, b.im)
+}
+
+#[test]
+fn test_real_slices() {
+    let x = grid_real();
+    let mut out = vec![0.0; x.len()];
+
+    li0_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li0())));
+    li1_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li1())));
+    li2_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li2())));
+    li3_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li3())));
+    li4_slice(&x, &mut out);
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li4())));
+
+    for n in -5..=12 {
+        li_slice(n, &x, &mut out);
+        assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li(n))));
+    }
+}
+
+#[test]
+fn test_complex_slices() {
+    let z = grid_complex();
+    let mut out = vec![Complex::new(0.0, 0.0); z.len()];
+
+    li0_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li0())));
+    li1_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li1())));
+    li2_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li2())));
+    li3_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li3())));
+    li4_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li4())));
+    li5_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li5())));
+    li6_slice(&z, &mut out);
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li6())));
+
+    for n in [-3, 0, 1, 7, 10, 20] {
+        li_slice_complex(n, &z, &mut out);
+        assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li(n))));
+    }
+}
+
+#[test]
+fn test_arrays() {
+    let x = Array2::from_shape_fn((120, 130), |(i, j)| 0.05*(i as f64) - 0.04*(j as f64) + 0.001);
+
+    // non-contiguous input and output views
+    let xt = x.t();
+    let mut out = Array2::zeros((130, 120));
+    li2_array(xt, out.view_mut());
+    assert!(xt.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li2())));
+    li3_array(xt, out.view_mut());
+    assert!(xt.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li3())));
+    li_array(9, xt, out.view_mut());
+    assert!(xt.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li(9))));
+
+    let mut out = Array2::zeros((120, 130));
+    li0_array(x.view(), out.view_mut());
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li0())));
+    li1_array(x.view(), out.view_mut());
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li1())));
+    li4_array(x.view(), out.view_mut());
+    assert!(x.iter().zip(out.iter()).all(|(x, l)| same(*l, x.li4())));
+
+    let z = Array3::from_shape_fn((20, 20, 20), |(i, j, k)| Complex::new(0.2*(i as f64) - 2.0, 0.2*(j as f64) - 0.1*(k as f64)));
+    let mut out = Array3::from_elem((20, 20, 20), Complex::new(0.0, 0.0));
+    li5_array(z.view(), out.view_mut());
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li5())));
+    li6_array(z.view(), out.view_mut());
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li6())));
+    li_array_complex(12, z.view(), out.view_mut());
+    assert!(z.iter().zip(out.iter()).all(|(z, l)| same_complex(*l, z.li(12))));
+
+    // every other element of a 1-dimensional array
+    let x = Array1::from_iter((0..1000).map(|k| 0.01*(k as f64) - 5.0));
+    let mut out = Array1::zeros(500);
+    li2_array(x.slice(ndarray::s![..;2]), out.view_mut());
+    assert!(x.iter().step_by(2).zip(out.iter()).all(|(x, l)| same(*l, x.li2())));
+}
+
+#[test]
+#[should_panic(expected = "li2_slice requires slices of equal length")]
+fn test_slice_length_mismatch() {
+    let mut out = [0.0; 2];
+    li2_slice(&[0.5, 0.5, 0.5], &mut out);
+}
+
+#[test]
+#[should_panic(expected = "li_array requires arrays of equal shape")]
+fn test_array_shape_mismatch() {
+    let x = Array2::<f64>::zeros((3, 4));
+    let mut out = Array2::<f64>::zeros((4, 3));
+    li_array(7, x.view(), out.view_mut());
+}
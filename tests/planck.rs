@@ -0,0 +1,90 @@
+use polylog::planck::{band_fraction, band_fraction_wavelength, planck_integral};
+mod common;
+
+
+#[test]
+fn test_values() {
+    struct Pl { n: i32, x: f64, res: f64 }
+
+    let values = vec![
+        Pl { n:  0, x:    0.5, res: 0.93275212956718857_f64 },
+        Pl { n:  0, x:   10.0, res: 4.540096037048921e-5_f64 },
+        Pl { n:  1, x:   0.01, res: 1.6349590390704764_f64 },
+        Pl { n:  3, x:  1e-05, res: 6.4939394022668288_f64 },
+        Pl { n:  3, x:    0.5, res: 6.4595659452261527_f64 },
+        Pl { n:  3, x:    1.0, res: 6.2691342142408909_f64 },
+        Pl { n:  3, x:   2.82, res: 4.197173513119179_f64 },
+        Pl { n:  3, x:   10.0, res: 0.062017505484999295_f64 },
+        Pl { n:  3, x:   50.0, res: 2.5614955230869607e-17_f64 },
+        Pl { n:  4, x:   -1.0, res: 24.522547606219194_f64 },
+        Pl { n:  5, x:    3.0, res: 110.78515823417273_f64 },
+        Pl { n: 12, x:   30.0, res: 80327.442451329387_f64 },
+        Pl { n:  2, x:  700.0, res: 4.8450647729566389e-299_f64 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(planck_integral(v.n, v.x)/v.res, 1.0_f64, 1e-14_f64);
+    }
+}
+
+
+#[test]
+fn test_limits() {
+    let pi4 = std::f64::consts::PI.powi(4);
+    assert!(planck_integral(3, f64::NAN).is_nan());
+    assert!(planck_integral(3, f64::INFINITY) == 0.0);
+    assert!(planck_integral(3, 1e3) == 0.0);
+    assert!(planck_integral(0, 0.0) == f64::INFINITY);
+    assert!(planck_integral(0, -1.0).is_nan());
+    assert_eq_float!(planck_integral(3, 0.0), pi4/15.0, 1e-15_f64);
+}
+
+
+#[test]
+fn test_continuity() {
+    // continuity at the boundaries between the different expansions
+    for n in 0..10 {
+        for &x in [std::f64::consts::LN_2, 1.0, 40.0].iter() {
+            let lo = planck_integral(n, x*(1.0 - 1e-15));
+            let hi = planck_integral(n, x*(1.0 + 1e-15));
+            assert_eq_float!(lo/hi, 1.0_f64, 1e-13_f64);
+        }
+    }
+}
+
+
+#[test]
+fn test_band_fraction() {
+    struct Band { x1: f64, x2: f64, res: f64 }
+
+    let values = vec![
+        Band { x1:   0.0, x2:   0.5, res: 0.0052931595001760745_f64 },
+        Band { x1:   1.0, x2:   3.0, res: 0.35839774920789028_f64 },
+        Band { x1:   2.0, x2:  10.0, res: 0.80930525750521714_f64 },
+        Band { x1:   4.0, x2:   8.0, res: 0.36381146897305927_f64 },
+        Band { x1:   0.5, x2:   5.0, res: 0.74923992970915413_f64 },
+        Band { x1:  20.0, x2:  30.0, res: 2.9596090895590797e-6_f64 },
+        Band { x1: 0.001, x2: 0.002, res: 3.5902072772439735e-10_f64 },
+        Band { x1:   3.0, x2:   3.5, res: 0.10636411123328108_f64 },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_float!(band_fraction(v.x1, v.x2)/v.res, 1.0_f64, 1e-14_f64);
+        assert_eq_float!(band_fraction(v.x2, v.x1)/v.res, -1.0_f64, 1e-14_f64);
+    }
+
+    assert!(band_fraction(0.0, f64::INFINITY) == 1.0);
+    assert!(band_fraction(2.0, 2.0) == 0.0);
+    assert!(band_fraction(-1.0, 2.0).is_nan());
+    assert!(band_fraction(f64::NAN, 2.0).is_nan());
+
+    // fraction of the solar radiation (T = 5778 K) in the visible band
+    assert_eq_float!(band_fraction_wavelength(380e-9, 750e-9, 5778.0), 0.43805591129840837_f64, 1e-14_f64);
+}
+
+
+#[test]
+#[should_panic]
+fn test_negative_order() {
+    planck_integral(-1, 1.0);
+}
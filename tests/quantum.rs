@@ -0,0 +1,105 @@
+use num::complex::Complex;
+use polylog::quantum::{faddeev, faddeev_asymptotic, q_pochhammer};
+mod common;
+
+
+#[test]
+fn test_q_pochhammer() {
+    struct Qp { x: Complex<f64>, q: Complex<f64>, res: Complex<f64> }
+
+    let values = vec![
+        Qp { x: Complex::new( 0.5, 0.0), q: Complex::new(0.5,  0.0 ), res: Complex::new(0.28878809508660242, 0.0) },
+        Qp { x: Complex::new( 0.3, 0.4), q: Complex::new(0.5, -0.3 ), res: Complex::new(0.41779546657221409, -0.21291597636103596) },
+        Qp { x: Complex::new( 2.5, 0.0), q: Complex::new(0.9,  0.0 ), res: Complex::new(-2.128829084627686e-10, 0.0) },
+        Qp { x: Complex::new(-1.0, 1.0), q: Complex::new(0.9,  0.05), res: Complex::new(-948.84040089608452, -20710.553218631530) },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_complex!(q_pochhammer(v.x, v.q), v.res, 1e-13_f64);
+    }
+
+    let zero = Complex::new(0.0, 0.0);
+    let x = Complex::new(0.7, -0.2);
+    let q = Complex::new(0.3, 0.4);
+    assert!(q_pochhammer(zero, q) == Complex::new(1.0, 0.0));
+    assert_eq_complex!(q_pochhammer(x, zero), 1.0 - x, 1e-15_f64);
+    assert!(q_pochhammer(x, Complex::new(1.0, 0.0)).is_nan());
+    assert!(q_pochhammer(Complex::new(f64::INFINITY, 0.0), Complex::new(0.5, 0.0)).is_nan());
+    assert!(q_pochhammer(Complex::new(1.0, f64::NEG_INFINITY), q).is_nan());
+    assert!(q_pochhammer(Complex::new(1.0, 0.0), q) == zero);
+
+    // (x;q)_Inf = (1 - x) (x q;q)_Inf
+    assert_eq_complex!(q_pochhammer(x, q), (1.0 - x)*q_pochhammer(x*q, q), 1e-15_f64);
+}
+
+
+#[test]
+fn test_faddeev() {
+    struct Fad { b: f64, z: Complex<f64>, res: Complex<f64> }
+
+    let values = vec![
+        Fad { b: 1.0, z: Complex::new( 0.0,  0.0), res: Complex::new(0.96592582628906829, 0.25881904510252076) },
+        Fad { b: 1.0, z: Complex::new( 0.3, -0.2), res: Complex::new(1.2497583154795951, 0.91680804742881894) },
+        Fad { b: 0.7, z: Complex::new(-0.5,  0.1), res: Complex::new(0.97908079784588714, 0.045398446005466868) },
+        Fad { b: 0.5, z: Complex::new(-1.0,  0.3), res: Complex::new(0.97584921380943824, 0.017653942041265186) },
+        Fad { b: 0.3, z: Complex::new(-0.2,  0.0), res: Complex::new(0.48814189134757526, 0.87276428313239997) },
+        Fad { b: 2.0, z: Complex::new(-0.4, -0.6), res: Complex::new(1.2192427200724546, -0.053074906326034063) },
+        Fad { b: 0.5, z: Complex::new(-5.0,  0.2), res: Complex::new(0.99999993736430611, 8.6210634508627128e-8) },
+        Fad { b: 1.0, z: Complex::new( 4.0,  0.1), res: Complex::new(0.071387864094549476, 0.038277836876539337) },
+        Fad { b: 0.9, z: Complex::new(-2.5, -0.3), res: Complex::new(1.0000006228368814, -7.1036404973630787e-8) },
+    ];
+
+    for v in values.into_iter() {
+        assert_eq_complex!(faddeev(v.b, v.z), v.res, 1e-14_f64);
+        assert_eq_complex!(faddeev(1.0/v.b, v.z), v.res, 1e-14_f64);
+        assert_eq_complex!(faddeev(-v.b, v.z), v.res, 1e-14_f64);
+    }
+
+    assert!(faddeev(0.0, Complex::new(1.0, 0.0)).is_nan());
+    assert!(faddeev(1.0, Complex::new(f64::NAN, 0.0)).is_nan());
+    assert!(faddeev(0.5, Complex::new(-100.0, 0.3)) == Complex::new(1.0, 0.0));
+}
+
+
+#[test]
+fn test_faddeev_functional_equations() {
+    let pi = std::f64::consts::PI;
+    let i = Complex::new(0.0, 1.0);
+
+    for &b in [0.3_f64, 0.6, 1.0].iter() {
+        for &z in [Complex::new(0.3, 0.1), Complex::new(-0.8, 0.4), Complex::new(1.5, -0.2), Complex::new(0.2, 1.5)].iter() {
+            // Phi_b(z - i b^(+-1)/2) = (1 + e^(2 pi b^(+-1) z)) Phi_b(z + i b^(+-1)/2)
+            for &c in [b, 1.0/b].iter() {
+                let lhs = faddeev(b, z - 0.5*i*c);
+                let rhs = (1.0 + (2.0*pi*c*z).exp())*faddeev(b, z + 0.5*i*c);
+                assert_eq_complex!(lhs, rhs, 1e-14_f64);
+            }
+
+            // Phi_b(z) Phi_b(-z) = Phi_b(0)^2 e^(i pi z^2)
+            let phi02 = (i*pi*(b*b + 1.0/(b*b))/12.0).exp();
+            assert_eq_complex!(faddeev(b, z)*faddeev(b, -z), phi02*(i*pi*z*z).exp(), 1e-14_f64);
+        }
+    }
+}
+
+
+#[test]
+fn test_faddeev_asymptotic() {
+    let pi = std::f64::consts::PI;
+    let x = Complex::new(0.5, 0.2);
+
+    for &b in [0.2_f64, 0.1, 0.05].iter() {
+        let exact = faddeev(b, x/(2.0*pi*b));
+        let mut old_err = f64::INFINITY;
+
+        // the error decreases with the order of the expansion
+        for order in 0..3 {
+            let err = (faddeev_asymptotic(b, x, order) - exact).norm()/exact.norm();
+            assert!(err < old_err);
+            old_err = err;
+        }
+    }
+
+    let b = 0.05;
+    assert_eq_complex!(faddeev_asymptotic(b, x, 5), faddeev(b, x/(2.0*pi*b)), 1e-14_f64);
+}
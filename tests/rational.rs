@@ -0,0 +1,77 @@
+use num::{BigInt, BigRational, ToPrimitive, Zero};
+use polylog::Li;
+use polylog::rational::{li_neg_exact, li_neg_rational};
+mod common;
+
+
+/// parses a decimal number like "-4.9" or "0." into an exact rational
+fn parse_rational(s: &str) -> BigRational {
+    let (sgn, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    let digits = format!("{}{}", int, frac).parse::<BigInt>().unwrap();
+    let den = num::pow(BigInt::from(10), frac.len());
+    BigRational::new(BigInt::from(sgn)*digits, den)
+}
+
+
+#[test]
+fn test_rational() {
+    let (p, d) = li_neg_rational(0);
+    assert_eq!(p, vec![BigInt::from(0), BigInt::from(1)]);
+    assert_eq!(d, 1);
+
+    let (p, d) = li_neg_rational(1);
+    assert_eq!(p, vec![BigInt::from(0), BigInt::from(1)]);
+    assert_eq!(d, 2);
+
+    let (p, d) = li_neg_rational(4);
+    let c: Vec<BigInt> = [0, 1, 11, 11, 1].iter().map(|&x| BigInt::from(x)).collect();
+    assert_eq!(p, c);
+    assert_eq!(d, 5);
+}
+
+
+#[test]
+fn test_pole() {
+    for n in 0..10 {
+        assert!(li_neg_exact(n, &BigRational::from_integer(BigInt::from(1))).is_none());
+        assert_eq!(li_neg_exact(n, &BigRational::zero()), Some(BigRational::zero()));
+    }
+}
+
+
+#[test]
+fn test_values() {
+    let eps = 1e-15_f64;
+
+    for n in 1..=10 {
+        let filename = format!("Li-{}.txt", n);
+        let values = common::read_data_file::<f64>(&filename).unwrap();
+
+        for &(v, res) in values.iter().filter(|(v, _)| v.im == 0.0) {
+            let z = parse_rational(&format!("{:?}", v.re));
+            let exact = li_neg_exact(n, &z).unwrap().to_f64().unwrap();
+            assert_eq_float!(exact, res.re, eps);
+            assert_eq_float!(v.re.li(-(n as i32)), exact, 1e-9_f64);
+        }
+    }
+}
+
+
+#[test]
+fn test_large_order() {
+    let values = common::read_data_file::<f64>("Li-100.txt").unwrap();
+
+    for &(v, res) in values.iter().filter(|(v, _)| v.im == 0.0) {
+        let z = parse_rational(&format!("{:?}", v.re));
+        let exact = li_neg_exact(100, &z).unwrap().to_f64().unwrap();
+        if res.re.is_infinite() {
+            assert!(exact == res.re);
+        } else {
+            assert_eq_float!(exact, res.re, 1e-15_f64);
+        }
+    }
+}
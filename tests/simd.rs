@@ -0,0 +1,91 @@
+use polylog::{Li2, Li3};
+use polylog::simd::{li2_slice, li2_x4, li2_x8, li3_slice, li3_x4, li3_x8};
+
+/// returns the unit in the last place of x
+fn ulp(x: f64) -> f64 {
+    let x = x.abs();
+    f64::from_bits(x.to_bits() + 1) - x
+}
+
+fn grid() -> Vec<f64> {
+    let mut x = vec![];
+    let mut s: u64 = 12345;
+    for _ in 0..200_000 {
+        // xorshift
+        s ^= s << 13; s ^= s >> 7; s ^= s << 17;
+        let u = (s >> 11) as f64/(1u64 << 53) as f64;
+        let sgn = if s & 1 == 0 { 1.0 } else { -1.0 };
+        x.push(sgn*(40.0*u - 20.0).exp());
+        x.push(8.0*u - 4.0);
+        x.push(2.0 + 1e4*u);
+    }
+    x
+}
+
+/// returns the scale of the cancelling terms ln(x)^k/k! for x > 2,
+/// relative to which the results are compared
+fn scale(x: f64, k: i32) -> f64 {
+    if x > 2.0 {
+        let l = x.ln();
+        l.powi(k)/f64::from((1..=k).product::<i32>())
+    } else {
+        0.0
+    }
+}
+
+#[test]
+fn test_li2_slice() {
+    let mut x = grid();
+    // close to the zero of Re[Li2(x)]
+    x.extend((0..10_000).map(|i| 12.0 + 1e-4*(i as f64)));
+    x.push(25.436942588934144);
+    let mut out = vec![0.0; x.len()];
+    li2_slice(&x, &mut out);
+
+    for (x, l) in x.iter().zip(out.iter()) {
+        let li = x.li2();
+        assert!((l - li).abs() <= 4.0*ulp(li.abs().max(scale(*x, 2))), "x = {}: {} != {}", x, l, li);
+    }
+}
+
+#[test]
+fn test_li3_slice() {
+    let mut x = grid();
+    // close to the zero of Re[Li3(x)]
+    x.extend((0..10_000).map(|i| 84.0 + 2.5e-4*(i as f64)));
+    x.extend([188.5160644027309, 821.1721937910958, 91.1026].iter());
+    let mut out = vec![0.0; x.len()];
+    li3_slice(&x, &mut out);
+
+    for (x, l) in x.iter().zip(out.iter()) {
+        let li = x.li3();
+        assert!((l - li).abs() <= 7.0*ulp(li.abs().max(scale(*x, 3))), "x = {}: {} != {}", x, l, li);
+    }
+}
+
+#[test]
+fn test_special_values() {
+    let x = [-1.0, -0.0, 0.0, 0.5, 1.0, 2.0, std::f64::INFINITY, std::f64::NAN];
+    let li2 = li2_x8(x);
+    let li3 = li3_x8(x);
+
+    for k in 0..5 {
+        assert!(li2[k] == x[k].li2() && li2[k].is_sign_negative() == x[k].li2().is_sign_negative());
+        assert!(li3[k] == x[k].li3() && li3[k].is_sign_negative() == x[k].li3().is_sign_negative());
+    }
+
+    assert!(li2[6] == std::f64::NEG_INFINITY && li3[6] == std::f64::NEG_INFINITY);
+    assert!(li2[7].is_nan() && li3[7].is_nan());
+    assert!(li2_x4([-1.0, 0.0, 1.0, -0.0]) == [(-1.0_f64).li2(), 0.0, 1.0_f64.li2(), -0.0]);
+    assert!(li3_x4([-1.0, 0.0, 1.0, 0.5]) == [(-1.0_f64).li3(), 0.0, 1.0_f64.li3(), 0.5_f64.li3()]);
+
+    // remainder lanes are evaluated like the full ones
+    let x: Vec<f64> = (0..13).map(|i| 0.3*(i as f64) - 1.7).collect();
+    let mut out = vec![0.0; x.len()];
+    li2_slice(&x, &mut out);
+    for (i, xi) in x.iter().enumerate() {
+        let mut o = [0.0];
+        li2_slice(&[*xi], &mut o);
+        assert!(o[0] == out[i]);
+    }
+}